@@ -0,0 +1,162 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::Error;
+
+/// The answer to a part, whatever type the day solves it in.
+///
+/// Integers are kept as `Int` whenever they fit in an `i64`, so that answers of the same
+/// value compare equal however they were built. Use the `From` conversions or parsing
+/// rather than picking the variant by hand.
+#[derive(Debug, Clone)]
+pub enum Answer {
+    Int(i64),
+    /// Too wide for an `i64`, such as a large `u64` or `u128`
+    WideInt(i128),
+    /// Anything that is not a number, such as letters read off a grid
+    Text(String),
+}
+
+impl Answer {
+    fn from_wide(value: i128) -> Answer {
+        return match i64::try_from(value) {
+            Ok(value) => Answer::Int(value),
+            Err(_) => Answer::WideInt(value),
+        };
+    }
+
+    /// The value as a wide integer, for comparing integers across variants
+    fn as_wide(&self) -> Option<i128> {
+        return match self {
+            Answer::Int(value) => Some(*value as i128),
+            Answer::WideInt(value) => Some(*value),
+            Answer::Text(_) => None,
+        };
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Answer) -> bool {
+        return match (self, other) {
+            (Answer::Text(text), Answer::Text(other)) => text == other,
+            _ => self.as_wide().is_some() && self.as_wide() == other.as_wide(),
+        };
+    }
+}
+
+impl Eq for Answer {}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            Answer::Int(value) => value.fmt(f),
+            Answer::WideInt(value) => value.fmt(f),
+            Answer::Text(text) => f.pad(text),
+        };
+    }
+}
+
+/// Integers become `Int` or `WideInt`, and anything else `Text`. An answer has to be a
+/// single word to be recorded in the answers file, so empty text and text with whitespace
+/// are rejected.
+impl FromStr for Answer {
+    type Err = Error;
+
+    fn from_str(text: &str) -> Result<Answer, Error> {
+        if text.is_empty() || text.contains(char::is_whitespace) {
+            return Err(Error::new(format!("'{}' is not a valid answer, expected a single word", text)));
+        }
+
+        if let Ok(value) = text.parse::<i128>() {
+            return Ok(Answer::from_wide(value));
+        }
+
+        return Ok(Answer::Text(text.to_owned()));
+    }
+}
+
+macro_rules! answer_from_int {
+    ($($int:ty),*) => {
+        $(
+            impl From<$int> for Answer {
+                fn from(value: $int) -> Answer {
+                    return Answer::Int(value as i64);
+                }
+            }
+        )*
+    };
+}
+
+answer_from_int!(i8, i16, i32, i64, u8, u16, u32);
+
+macro_rules! answer_from_wide_int {
+    ($($int:ty),*) => {
+        $(
+            impl From<$int> for Answer {
+                fn from(value: $int) -> Answer {
+                    return Answer::from_wide(value as i128);
+                }
+            }
+        )*
+    };
+}
+
+answer_from_wide_int!(u64, usize, i128);
+
+impl From<u128> for Answer {
+    fn from(value: u128) -> Answer {
+        return match i128::try_from(value) {
+            Ok(value) => Answer::from_wide(value),
+            // wider than any variant holds, but still a valid answer
+            Err(_) => Answer::Text(value.to_string()),
+        };
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Answer {
+        return Answer::Text(text);
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Answer {
+        return Answer::Text(text.to_owned());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Answer;
+
+    #[test]
+    fn integers_are_equal_whatever_their_type() {
+        assert_eq!(Answer::from(46u8), Answer::from(46i64));
+        assert_eq!(Answer::from(46usize), Answer::Int(46));
+        assert_eq!(Answer::WideInt(46), Answer::Int(46));
+        assert_ne!(Answer::from("46"), Answer::Int(46));
+    }
+
+    #[test]
+    fn only_integers_too_wide_for_i64_are_wide() {
+        assert_eq!(Answer::from(u64::MAX), Answer::WideInt(u64::MAX as i128));
+        assert!(matches!(Answer::from(i64::MAX as u64), Answer::Int(_)));
+        assert!(matches!(Answer::from(u128::MAX), Answer::Text(_)));
+    }
+
+    #[test]
+    fn parsing_picks_the_variant() {
+        assert!(matches!("-15".parse::<Answer>(), Ok(Answer::Int(-15))));
+        assert!(matches!("18446744073709551615".parse::<Answer>(), Ok(Answer::WideInt(_))));
+        assert!(matches!("EHZPZHP".parse::<Answer>(), Ok(Answer::Text(_))));
+        assert!("".parse::<Answer>().is_err());
+        assert!("two words".parse::<Answer>().is_err());
+    }
+
+    #[test]
+    fn display_round_trips_through_parsing() {
+        for answer in [Answer::Int(-15), Answer::from(u64::MAX), Answer::from("EHZPZHP")] {
+            assert_eq!(answer.to_string().parse::<Answer>().unwrap(), answer);
+        }
+    }
+}
@@ -0,0 +1,277 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::solution::Answer;
+
+/// Placeholder used in the answers file for a part whose answer is not known yet
+pub const UNKNOWN_ANSWER: &str = "?";
+
+/// File name the answers for a day's puzzle input are recorded under, as opposed to its samples
+pub const PUZZLE_INPUT: &str = "input.txt";
+
+/// The answers file that ships with the crate
+pub fn default_answers_path() -> PathBuf {
+    return PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("answers.txt");
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Status {
+    Pass,
+    Fail,
+    Unknown,
+    /// Stopped for running past the time limit, so there is no answer to check
+    TimedOut,
+    /// The known answer was solved for an input with a different checksum, so the answer
+    /// cannot be checked against it
+    DifferentInput,
+    /// Panicked, so there is no answer to check
+    Crashed,
+    /// The input did not fit the puzzle, so there is no answer to check
+    Error,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            Status::Pass => "pass",
+            Status::Fail => "fail",
+            Status::Unknown => "unknown",
+            Status::DifferentInput => "different input",
+            Status::TimedOut => "timeout",
+            Status::Crashed => "crashed",
+            Status::Error => "error",
+        };
+
+        return f.pad(text);
+    }
+}
+
+type AnswerKey = (u8, u8, String);
+
+/// Checksum of an input as recorded in the answers file: the 64-bit FNV-1a hash of the
+/// normalised input, in hex
+pub fn checksum(input: &str) -> String {
+    let mut hash: u64 = 0xcbf29ce484222325;
+
+    for byte in input.bytes() {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+
+    return format!("{:016x}", hash);
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct KnownAnswer {
+    pub answer: Answer,
+    /// Checksum of the input the answer was solved for, when recorded
+    pub checksum: Option<String>,
+}
+
+/// Known answers keyed by day, part and the name of the input file they were solved for.
+///
+/// The file format is one answer per line: `<day> <part> <input file> <answer> [checksum]`.
+/// Blank lines and lines starting with `#` are ignored, and an answer of `?` records a
+/// part whose answer is not known yet. The checksum tells a wrong answer apart from an
+/// answer for somebody else's input.
+#[derive(Debug, Default)]
+pub struct Answers {
+    entries: HashMap<AnswerKey, KnownAnswer>,
+}
+
+impl Answers {
+    pub fn parse(text: &str) -> Result<Answers, String> {
+        let mut entries = HashMap::<AnswerKey, KnownAnswer>::new();
+
+        for (ix, line) in text.lines().enumerate() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let fields = line.split_whitespace().collect::<Vec<_>>();
+
+            let (day, part, input, answer, checksum) = match fields.as_slice() {
+                [day, part, input, answer] => (day, part, input, answer, None),
+                [day, part, input, answer, checksum] => (day, part, input, answer, Some(checksum)),
+                _ => return Err(format!("line {}: expected '<day> <part> <input file> <answer> [checksum]'", ix + 1)),
+            };
+
+            let day = day
+                .parse::<u8>()
+                .map_err(|_| format!("line {}: invalid day '{}'", ix + 1, day))?;
+            let part = part
+                .parse::<u8>()
+                .map_err(|_| format!("line {}: invalid part '{}'", ix + 1, part))?;
+
+            if *answer != UNKNOWN_ANSWER {
+                let known = KnownAnswer {
+                    answer: answer.parse().map_err(|error| format!("line {}: {}", ix + 1, error))?,
+                    checksum: checksum.map(|checksum| checksum.to_string()),
+                };
+
+                entries.insert((day, part, input.to_string()), known);
+            }
+        }
+
+        return Ok(Answers { entries });
+    }
+
+    pub fn load(path: &Path) -> io::Result<Answers> {
+        let text = fs::read_to_string(path)?;
+
+        return Answers::parse(&text).map_err(|error| {
+            io::Error::new(io::ErrorKind::InvalidData, format!("{}: {}", path.display(), error))
+        });
+    }
+
+    pub fn expected(&self, day: u8, part: u8, input: &str) -> Option<&KnownAnswer> {
+        return self.entries.get(&(day, part, input.to_owned()));
+    }
+
+    /// Takes the answers for the puzzle inputs from a profile's answers, keeping the sample
+    /// answers, which are the same for everyone
+    pub fn with_profile(mut self, profile: Answers) -> Answers {
+        self.entries.retain(|(_, _, input), _| input != PUZZLE_INPUT);
+        self.entries.extend(profile.entries);
+
+        return self;
+    }
+
+    /// The answer recorded for an input with the given checksum, whatever its file name
+    pub fn expected_for_checksum(&self, day: u8, part: u8, checksum: &str) -> Option<&KnownAnswer> {
+        return self
+            .entries
+            .iter()
+            .find(|((entry_day, entry_part, _), known)| {
+                *entry_day == day && *entry_part == part && known.checksum.as_deref() == Some(checksum)
+            })
+            .map(|(_, known)| known);
+    }
+
+    /// The answer to check an input that is only known by its checksum against: the one
+    /// recorded for that checksum, or else the puzzle input's, when its own checksum is
+    /// recorded to tell the two inputs apart
+    pub fn expected_for_input(&self, day: u8, part: u8, checksum: &str) -> Option<&KnownAnswer> {
+        return self
+            .expected_for_checksum(day, part, checksum)
+            .or_else(|| self.expected(day, part, PUZZLE_INPUT).filter(|known| known.checksum.is_some()));
+    }
+}
+
+/// Checks an answer against the known one, for an input with the given checksum
+pub fn status(answer: &Answer, expected: Option<&KnownAnswer>, checksum: &str) -> Status {
+    return match expected {
+        Some(expected) if expected.answer == *answer => Status::Pass,
+        Some(expected) if expected.checksum.as_deref().is_some_and(|expected| expected != checksum) => {
+            Status::DifferentInput
+        }
+        Some(_) => Status::Fail,
+        None => Status::Unknown,
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{checksum, status, Answers, KnownAnswer, Status};
+    use crate::solution::Answer;
+
+    const ANSWERS: &str = "\
+# day part input answer checksum
+
+1 1 input.txt 142 00000000000000aa
+1 2 input.txt 281
+2 1 sample1.txt 8
+2 2 input.txt ?
+";
+
+    fn known(answer: i64, checksum: Option<&str>) -> KnownAnswer {
+        return KnownAnswer { answer: Answer::Int(answer), checksum: checksum.map(String::from) };
+    }
+
+    #[test]
+    fn checksum_column_is_optional() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+
+        assert_eq!(answers.expected(1, 1, "input.txt"), Some(&known(142, Some("00000000000000aa"))));
+        assert_eq!(answers.expected(1, 2, "input.txt"), Some(&known(281, None)));
+        assert_eq!(answers.expected(2, 1, "sample1.txt"), Some(&known(8, None)));
+    }
+
+    #[test]
+    fn unknown_answers_are_not_recorded() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+
+        assert_eq!(answers.expected(2, 2, "input.txt"), None);
+    }
+
+    #[test]
+    fn malformed_lines_are_errors_naming_the_line() {
+        assert_eq!(
+            Answers::parse("1 1 input.txt").unwrap_err(),
+            "line 1: expected '<day> <part> <input file> <answer> [checksum]'"
+        );
+        assert_eq!(
+            Answers::parse("# header\n1 1 input.txt 142 aa extra").unwrap_err(),
+            "line 2: expected '<day> <part> <input file> <answer> [checksum]'"
+        );
+        assert_eq!(Answers::parse("x 1 input.txt 142").unwrap_err(), "line 1: invalid day 'x'");
+        assert_eq!(Answers::parse("1 300 input.txt 142").unwrap_err(), "line 1: invalid part '300'");
+    }
+
+    #[test]
+    fn status_compares_the_answer_and_the_input() {
+        let expected = known(142, Some("00000000000000aa"));
+
+        assert_eq!(status(&Answer::Int(142), Some(&expected), "00000000000000aa"), Status::Pass);
+        assert_eq!(status(&Answer::Int(143), Some(&expected), "00000000000000aa"), Status::Fail);
+        assert_eq!(status(&Answer::Int(143), Some(&expected), "00000000000000bb"), Status::DifferentInput);
+        assert_eq!(status(&Answer::Int(143), Some(&known(142, None)), "00000000000000bb"), Status::Fail);
+        assert_eq!(status(&Answer::Int(143), None, "00000000000000aa"), Status::Unknown);
+    }
+
+    #[test]
+    fn checksum_is_64_bit_fnv_1a() {
+        assert_eq!(checksum(""), "cbf29ce484222325");
+        assert_eq!(checksum("a"), "af63dc4c8601ec8c");
+        assert_eq!(checksum("foobar"), "85944171f73967e8");
+    }
+
+    #[test]
+    fn expected_for_checksum_ignores_the_file_name() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+
+        assert_eq!(answers.expected_for_checksum(1, 1, "00000000000000aa"), Some(&known(142, Some("00000000000000aa"))));
+        assert_eq!(answers.expected_for_checksum(1, 2, "00000000000000aa"), None);
+        assert_eq!(answers.expected_for_checksum(1, 1, "00000000000000bb"), None);
+    }
+
+    #[test]
+    fn input_with_a_recorded_checksum_is_checked_against_its_answer() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+        let expected = answers.expected_for_input(1, 1, "00000000000000aa");
+
+        assert_eq!(status(&Answer::Int(142), expected, "00000000000000aa"), Status::Pass);
+        assert_eq!(status(&Answer::Int(143), expected, "00000000000000aa"), Status::Fail);
+    }
+
+    #[test]
+    fn input_with_another_checksum_is_a_different_input() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+        let expected = answers.expected_for_input(1, 1, "00000000000000bb");
+
+        assert_eq!(status(&Answer::Int(143), expected, "00000000000000bb"), Status::DifferentInput);
+    }
+
+    #[test]
+    fn input_without_a_recorded_checksum_to_compare_is_unknown() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+        let expected = answers.expected_for_input(1, 2, "00000000000000bb");
+
+        assert_eq!(status(&Answer::Int(282), expected, "00000000000000bb"), Status::Unknown);
+    }
+}
@@ -0,0 +1,272 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use crate::error;
+use crate::report::format_duration;
+use crate::solution::Puzzle;
+
+/// The baseline file used when none is given on the command line. It is git-ignored, as
+/// timings only make sense on the machine they were taken on.
+pub fn default_baseline_path() -> PathBuf {
+    return PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("bench-baseline.txt");
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchSettings {
+    pub warmup: u32,
+    pub iterations: u32,
+    /// How much slower than the baseline median, in percent, counts as a regression
+    pub threshold: f64,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &mut [Duration]) -> Stats {
+        assert!(!samples.is_empty(), "No samples to compute statistics from");
+
+        samples.sort();
+
+        let total = samples.iter().sum::<Duration>();
+        // nearest rank, so that a small number of runs still reports one that happened
+        let p95_rank = ((samples.len() as f64) * 0.95).ceil() as usize;
+
+        return Stats {
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            mean: total / samples.len() as u32,
+            p95: samples[p95_rank.max(1) - 1],
+        };
+    }
+}
+
+/// What was measured: the parse step, or solving one part from the parsed input
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Step {
+    Parse,
+    Part(u8),
+}
+
+impl Step {
+    fn name(&self) -> String {
+        return match self {
+            Step::Parse => String::from("parse"),
+            Step::Part(part) => format!("part{}", part),
+        };
+    }
+
+    fn from_name(name: &str) -> Option<Step> {
+        if name == "parse" {
+            return Some(Step::Parse);
+        }
+
+        return name.strip_prefix("part")?.parse::<u8>().ok().map(Step::Part);
+    }
+}
+
+/// Median timings from an earlier benchmark, keyed by day and step.
+///
+/// Stored as one line per step: `<day> <parse|partN> <median in nanoseconds>`.
+#[derive(Debug, Default)]
+pub struct Baseline {
+    entries: BTreeMap<(u8, Step), Duration>,
+}
+
+impl Baseline {
+    /// Loads the baseline, treating a missing file as an empty one
+    pub fn load(path: &Path) -> io::Result<Baseline> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Baseline::default()),
+            Err(error) => return Err(error),
+        };
+
+        let mut entries = BTreeMap::<(u8, Step), Duration>::new();
+
+        for (ix, line) in text.lines().enumerate() {
+            let fields = line.split_whitespace().collect::<Vec<_>>();
+
+            let entry = match fields.as_slice() {
+                [] => continue,
+                [day, step, nanos] => day
+                    .parse::<u8>()
+                    .ok()
+                    .zip(Step::from_name(step))
+                    .zip(nanos.parse::<u64>().ok()),
+                _ => None,
+            };
+
+            let Some((key, nanos)) = entry else {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{}: line {}: expected '<day> <parse|partN> <nanoseconds>'", path.display(), ix + 1),
+                ));
+            };
+
+            entries.insert(key, Duration::from_nanos(nanos));
+        }
+
+        return Ok(Baseline { entries });
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let text = self
+            .entries
+            .iter()
+            .map(|((day, step), median)| format!("{} {} {}\n", day, step.name(), median.as_nanos()))
+            .collect::<String>();
+
+        return fs::write(path, text);
+    }
+
+    pub fn get(&self, day: u8, step: Step) -> Option<Duration> {
+        return self.entries.get(&(day, step)).copied();
+    }
+
+    pub fn set(&mut self, day: u8, step: Step, median: Duration) {
+        self.entries.insert((day, step), median);
+    }
+}
+
+#[derive(Debug)]
+pub struct Measurement {
+    pub step: Step,
+    pub stats: Stats,
+}
+
+/// Times parsing the input and solving each part, `settings.iterations` times each after
+/// `settings.warmup` untimed runs
+pub fn measure(
+    puzzle: &dyn Puzzle,
+    parts: &[u8],
+    raw_input: &str,
+    settings: &BenchSettings,
+) -> error::Result<Vec<Measurement>> {
+    let mut timings = vec![Vec::<Duration>::with_capacity(settings.iterations as usize); parts.len() + 1];
+
+    for iteration in 0..settings.warmup + settings.iterations {
+        let timed = iteration >= settings.warmup;
+
+        let parse_start = Instant::now();
+        let parsed = puzzle.parse(raw_input)?;
+        let parse_time = parse_start.elapsed();
+
+        if timed {
+            timings[0].push(parse_time);
+        }
+
+        for (ix, part) in parts.iter().enumerate() {
+            let solve_start = Instant::now();
+            let answer = puzzle.solve(*part, parsed.as_ref())?;
+            let solve_time = solve_start.elapsed();
+
+            // keeps the answer from being optimised away
+            std::hint::black_box(answer);
+
+            if timed {
+                timings[ix + 1].push(solve_time);
+            }
+        }
+    }
+
+    let steps = [Step::Parse].into_iter().chain(parts.iter().map(|part| Step::Part(*part)));
+
+    return Ok(steps
+        .zip(timings.iter_mut())
+        .map(|(step, samples)| Measurement { step, stats: Stats::from_samples(samples) })
+        .collect());
+}
+
+/// Renders the measurements next to the baseline, returning the table and whether any step
+/// regressed beyond the threshold
+pub fn compare(day: u8, measurements: &[Measurement], baseline: &Baseline, threshold: f64) -> (String, bool) {
+    let mut table = format!(
+        "{:<8}{:>12}{:>12}{:>12}{:>12}{:>12}{:>10}\n",
+        "", "min", "median", "mean", "p95", "baseline", "change"
+    );
+    let mut regressed = false;
+
+    for measurement in measurements {
+        let stats = &measurement.stats;
+        let label = match measurement.step {
+            Step::Parse => String::from("parse"),
+            Step::Part(part) => format!("part {}", part),
+        };
+
+        let (previous, change, flag) = match baseline.get(day, measurement.step) {
+            Some(previous) if !previous.is_zero() => {
+                let change = (stats.median.as_secs_f64() / previous.as_secs_f64() - 1.0) * 100.0;
+                let is_regression = change > threshold;
+
+                regressed |= is_regression;
+
+                (
+                    format_duration(previous),
+                    format!("{:+.1}%", change),
+                    if is_regression { "  <- regression" } else { "" },
+                )
+            }
+            _ => (String::from("-"), String::from("-"), ""),
+        };
+
+        table.push_str(&format!(
+            "{:<8}{:>12}{:>12}{:>12}{:>12}{:>12}{:>10}{}\n",
+            label,
+            format_duration(stats.min),
+            format_duration(stats.median),
+            format_duration(stats.mean),
+            format_duration(stats.p95),
+            previous,
+            change,
+            flag
+        ));
+    }
+
+    return (table, regressed);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::Stats;
+
+    fn millis(values: &[u64]) -> Vec<Duration> {
+        return values.iter().map(|value| Duration::from_millis(*value)).collect();
+    }
+
+    #[test]
+    fn stats_sort_the_samples_first() {
+        let stats = Stats::from_samples(&mut millis(&[5, 1, 4, 2, 3]));
+
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.mean, Duration::from_millis(3));
+        assert_eq!(stats.p95, Duration::from_millis(5));
+    }
+
+    #[test]
+    fn p95_is_the_nearest_rank() {
+        let mut twenty = millis(&(1..=20).rev().collect::<Vec<_>>());
+        let mut forty = millis(&(1..=40).collect::<Vec<_>>());
+
+        // ranks 19 of 20 and 38 of 40, rather than an interpolated value between two runs
+        assert_eq!(Stats::from_samples(&mut twenty).p95, Duration::from_millis(19));
+        assert_eq!(Stats::from_samples(&mut forty).p95, Duration::from_millis(38));
+    }
+
+    #[test]
+    fn few_samples_report_the_slowest_as_p95() {
+        assert_eq!(Stats::from_samples(&mut millis(&[7])).p95, Duration::from_millis(7));
+        assert_eq!(Stats::from_samples(&mut millis(&[3, 9, 4])).p95, Duration::from_millis(9));
+    }
+}
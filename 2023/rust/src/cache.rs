@@ -0,0 +1,170 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::solution::Answer;
+
+/// The answer cache file. It is git-ignored, as it only holds answers this checkout has
+/// already worked out.
+pub fn default_cache_path() -> PathBuf {
+    return PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("answer-cache.txt");
+}
+
+type CacheKey = (u8, u8, String);
+
+#[derive(Debug, Clone, PartialEq)]
+struct CachedAnswer {
+    version: String,
+    answer: Answer,
+}
+
+/// Answers solved earlier, keyed by day, part and the checksum of the input they were
+/// solved from. An answer only counts for the solution version it was solved with, so
+/// bumping a day's [`crate::solution::Solution::VERSION`] makes it solve afresh.
+///
+/// Stored as one answer per line: `<day> <part> <checksum> <version> <answer>`.
+#[derive(Debug, Default)]
+pub struct AnswerCache {
+    entries: BTreeMap<CacheKey, CachedAnswer>,
+}
+
+impl AnswerCache {
+    /// Loads the cache, treating a missing file as an empty one
+    pub fn load(path: &Path) -> io::Result<AnswerCache> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(AnswerCache::default()),
+            Err(error) => return Err(error),
+        };
+
+        let mut entries = BTreeMap::<CacheKey, CachedAnswer>::new();
+
+        for (ix, line) in text.lines().enumerate() {
+            let fields = line.split_whitespace().collect::<Vec<_>>();
+
+            let entry = match fields.as_slice() {
+                [] => continue,
+                [day, part, checksum, version, answer] => {
+                    let key = day.parse::<u8>().ok().zip(part.parse::<u8>().ok());
+                    let answer = answer.parse::<Answer>().ok();
+
+                    key.zip(answer).map(|((day, part), answer)| {
+                        ((day, part, checksum.to_string()), CachedAnswer { version: version.to_string(), answer })
+                    })
+                }
+                _ => None,
+            };
+
+            let Some((key, cached)) = entry else {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!(
+                        "{}: line {}: expected '<day> <part> <checksum> <version> <answer>'",
+                        path.display(),
+                        ix + 1
+                    ),
+                ));
+            };
+
+            entries.insert(key, cached);
+        }
+
+        return Ok(AnswerCache { entries });
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let text = self
+            .entries
+            .iter()
+            .map(|((day, part, checksum), cached)| {
+                format!("{} {} {} {} {}\n", day, part, checksum, cached.version, cached.answer)
+            })
+            .collect::<String>();
+
+        return fs::write(path, text);
+    }
+
+    pub fn get(&self, day: u8, part: u8, checksum: &str, version: &str) -> Option<&Answer> {
+        return self
+            .entries
+            .get(&(day, part, checksum.to_owned()))
+            .filter(|cached| cached.version == version)
+            .map(|cached| &cached.answer);
+    }
+
+    /// Records an answer, replacing any solved by another version for the same input
+    pub fn insert(&mut self, day: u8, part: u8, checksum: &str, version: &str, answer: &Answer) {
+        let cached = CachedAnswer { version: version.to_owned(), answer: answer.clone() };
+
+        self.entries.insert((day, part, checksum.to_owned()), cached);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+    use std::io;
+    use std::path::PathBuf;
+    use std::process;
+
+    use super::AnswerCache;
+    use crate::solution::Answer;
+
+    /// A path in the temp directory that is unique to this test run
+    fn temp_path(name: &str) -> PathBuf {
+        return env::temp_dir().join(format!("aoc-2023-cache-{}-{}.txt", process::id(), name));
+    }
+
+    #[test]
+    fn saved_answers_load_back() {
+        let path = temp_path("round-trip");
+        let mut cache = AnswerCache::default();
+        cache.insert(1, 1, "00000000000000aa", "1", &Answer::Int(142));
+        cache.insert(1, 2, "00000000000000aa", "2", &Answer::WideInt(u64::MAX as i128));
+        cache.insert(3, 1, "00000000000000bb", "1", &Answer::from("ABC"));
+
+        cache.save(&path).unwrap();
+        let loaded = AnswerCache::load(&path);
+        fs::remove_file(&path).unwrap();
+        let loaded = loaded.unwrap();
+
+        assert_eq!(loaded.get(1, 1, "00000000000000aa", "1"), Some(&Answer::Int(142)));
+        assert_eq!(loaded.get(1, 2, "00000000000000aa", "2"), Some(&Answer::WideInt(u64::MAX as i128)));
+        assert_eq!(loaded.get(3, 1, "00000000000000bb", "1"), Some(&Answer::from("ABC")));
+        assert_eq!(loaded.get(3, 1, "00000000000000aa", "1"), None);
+    }
+
+    #[test]
+    fn answers_from_another_version_are_dropped() {
+        let mut cache = AnswerCache::default();
+        cache.insert(1, 1, "00000000000000aa", "1", &Answer::Int(142));
+
+        assert_eq!(cache.get(1, 1, "00000000000000aa", "2"), None);
+
+        cache.insert(1, 1, "00000000000000aa", "2", &Answer::Int(143));
+
+        assert_eq!(cache.get(1, 1, "00000000000000aa", "1"), None);
+        assert_eq!(cache.get(1, 1, "00000000000000aa", "2"), Some(&Answer::Int(143)));
+    }
+
+    #[test]
+    fn corrupt_file_is_invalid_data() {
+        let path = temp_path("corrupt");
+        fs::write(&path, "1 1 00000000000000aa 1 142\n1 x 00000000000000aa 1 142\n").unwrap();
+
+        let error = AnswerCache::load(&path).unwrap_err();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert!(error.to_string().contains("line 2"), "{}", error);
+    }
+
+    #[test]
+    fn missing_file_is_an_empty_cache() {
+        let cache = AnswerCache::load(&temp_path("missing")).unwrap();
+
+        assert!(cache.entries.is_empty());
+    }
+}
@@ -0,0 +1,119 @@
+use std::cell::RefCell;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+/// Payload a cancelled solver unwinds with, so the runner can tell a timeout from a crash
+#[derive(Debug)]
+pub struct Cancelled;
+
+/// Lets the runner stop a solver that is over its time limit.
+///
+/// Cancellation is cooperative: solvers take the token with [`current`] before their hot
+/// loops and call [`CancelToken::checkpoint`] inside them. The check is a single atomic
+/// load, so it is cheap enough to do on every iteration.
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancelToken {
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        return self.cancelled.load(Ordering::Relaxed);
+    }
+
+    /// Unwinds out of the solver once the token is cancelled
+    pub fn checkpoint(&self) {
+        if self.is_cancelled() {
+            panic::resume_unwind(Box::new(Cancelled));
+        }
+    }
+}
+
+thread_local! {
+    static CURRENT: RefCell<CancelToken> = RefCell::new(CancelToken::default());
+}
+
+/// The token of the part being solved on this thread. Rayon workers do not inherit it, so
+/// solvers that fan out take it first and hand it to their workers.
+pub fn current() -> CancelToken {
+    return CURRENT.with(|token| token.borrow().clone());
+}
+
+/// Runs `solve` with a fresh token installed for the current thread, cancelling the token
+/// once `limit` has passed. Returns `None` when the solver stopped because of that.
+pub fn run_with_limit<T>(limit: Option<Duration>, solve: impl FnOnce() -> T) -> Option<T> {
+    let token = CancelToken::default();
+    let previous = CURRENT.with(|current| current.replace(token.clone()));
+
+    let result = thread::scope(|scope| {
+        let (done, finished) = mpsc::channel::<()>();
+
+        if let Some(limit) = limit {
+            let token = token.clone();
+
+            // the watchdog wakes up when the limit passes, or as soon as `done` is dropped
+            scope.spawn(move || {
+                if let Err(RecvTimeoutError::Timeout) = finished.recv_timeout(limit) {
+                    token.cancel();
+                }
+            });
+        }
+
+        let result = panic::catch_unwind(AssertUnwindSafe(solve));
+        drop(done);
+
+        result
+    });
+
+    CURRENT.with(|current| current.replace(previous));
+
+    return match result {
+        Ok(answer) => Some(answer),
+        Err(payload) if payload.is::<Cancelled>() => None,
+        Err(payload) => panic::resume_unwind(payload),
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::panic;
+    use std::time::Duration;
+
+    use super::{current, run_with_limit};
+
+    #[test]
+    fn solver_past_the_limit_is_cancelled() {
+        let result = run_with_limit(Some(Duration::from_millis(10)), || {
+            let token = current();
+
+            loop {
+                token.checkpoint();
+            }
+        });
+
+        assert_eq!(result, Option::<()>::None);
+        assert!(!current().is_cancelled());
+    }
+
+    #[test]
+    fn solver_within_the_limit_gives_its_answer() {
+        assert_eq!(run_with_limit(Some(Duration::from_secs(60)), || 46), Some(46));
+        assert_eq!(run_with_limit(None, || 46), Some(46));
+    }
+
+    #[test]
+    fn other_panics_are_passed_on() {
+        let result = panic::catch_unwind(|| run_with_limit(Some(Duration::from_secs(60)), || panic!("bad input")));
+        let payload = result.unwrap_err();
+
+        assert_eq!(payload.downcast_ref::<&str>(), Some(&"bad input"));
+    }
+}
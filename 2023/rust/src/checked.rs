@@ -0,0 +1,50 @@
+use std::any;
+use std::fmt::Display;
+use std::ops::{Add, Mul, Sub};
+
+use num_traits::{CheckedAdd, CheckedMul, CheckedSub};
+
+use crate::error::{Error, Result};
+
+/// Whether the hot paths check their arithmetic, i.e. whether the crate was built with the
+/// `checked-arithmetic` feature
+pub const ENABLED: bool = cfg!(feature = "checked-arithmetic");
+
+fn overflow<T: Display>(operation: &str, a: T, operator: char, b: T) -> Error {
+    return Error::new(format!(
+        "Overflow in {}: {} {} {} does not fit in {}",
+        operation,
+        a,
+        operator,
+        b,
+        any::type_name::<T>()
+    ));
+}
+
+/// `a + b`. With the `checked-arithmetic` feature an overflow is reported as an error
+/// naming `operation`; without it the addition wraps in release builds as usual.
+pub fn add<T: Add<Output = T> + CheckedAdd + Display + Copy>(a: T, b: T, operation: &str) -> Result<T> {
+    if ENABLED {
+        return a.checked_add(&b).ok_or_else(|| overflow(operation, a, '+', b));
+    }
+
+    return Ok(a + b);
+}
+
+/// `a - b`, checked like [`add`]
+pub fn sub<T: Sub<Output = T> + CheckedSub + Display + Copy>(a: T, b: T, operation: &str) -> Result<T> {
+    if ENABLED {
+        return a.checked_sub(&b).ok_or_else(|| overflow(operation, a, '-', b));
+    }
+
+    return Ok(a - b);
+}
+
+/// `a * b`, checked like [`add`]
+pub fn mul<T: Mul<Output = T> + CheckedMul + Display + Copy>(a: T, b: T, operation: &str) -> Result<T> {
+    if ENABLED {
+        return a.checked_mul(&b).ok_or_else(|| overflow(operation, a, '*', b));
+    }
+
+    return Ok(a * b);
+}
@@ -0,0 +1,547 @@
+use std::env;
+use std::fmt;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::bench::{default_baseline_path, BenchSettings};
+use crate::input::InputSource;
+use crate::logging::Level;
+use crate::profile::{Profile, PROFILE_ENV};
+use crate::report::Format;
+
+#[derive(Debug, PartialEq)]
+pub enum RunTarget {
+    All,
+    Day { day: u8, part: Option<u8> },
+}
+
+#[derive(Debug, PartialEq)]
+pub struct RunOptions {
+    pub target: RunTarget,
+    pub input: InputSource,
+    /// Run against the given sample (starting at 1) instead of the puzzle input
+    pub sample: Option<u8>,
+    pub format: Format,
+    /// Solve days and parts concurrently on a pool of this many threads
+    pub jobs: Option<usize>,
+    /// Cancel any part that runs for longer than this
+    pub time_limit: Option<Duration>,
+    /// Reuse answers from the answer cache instead of solving those parts again
+    pub use_cache: bool,
+    /// Whose inputs and known answers to use, rather than the default inputs and the crate's answers
+    pub profile: Option<Profile>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct BenchOptions {
+    pub day: u8,
+    pub part: Option<u8>,
+    pub input: InputSource,
+    pub settings: BenchSettings,
+    pub baseline: PathBuf,
+    /// Overwrite the baseline with this run even if the day already has one
+    pub save_baseline: bool,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct BatchOptions {
+    /// Every file in this directory is solved as an input of the day
+    pub dir: PathBuf,
+    pub run: RunOptions,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Run(RunOptions),
+    /// Like `Run`, but reports whether each answer matches the known one
+    Verify(RunOptions),
+    Bench(BenchOptions),
+    /// Like `Run`, but for every input in a directory, tabulating the results
+    Batch(BatchOptions),
+    /// Generate the skeleton of a new day
+    New { day: u8 },
+    /// Check an input against the day's format without solving it
+    Lint { day: u8, input: InputSource },
+    Help,
+}
+
+#[derive(Debug)]
+pub struct UsageError(pub String);
+
+impl fmt::Display for UsageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+fn parse_number<T: FromStr>(value: &str, what: &str) -> Result<T, UsageError> {
+    return value
+        .parse::<T>()
+        .map_err(|_| UsageError(format!("invalid {}: '{}'", what, value)));
+}
+
+fn option_value<'a>(
+    args: &mut impl Iterator<Item = &'a String>,
+    option: &str,
+) -> Result<&'a str, UsageError> {
+    return args
+        .next()
+        .map(|value| value.as_str())
+        .ok_or_else(|| UsageError(format!("{} expects a value", option)));
+}
+
+fn parse_run(args: &[String], command: &str) -> Result<RunOptions, UsageError> {
+    let mut all = false;
+    let mut input_file = Option::<InputSource>::None;
+    let mut inputs_dir = Option::<PathBuf>::None;
+    let mut sample = Option::<u8>::None;
+    let mut format = Format::default();
+    let mut jobs = Option::<usize>::None;
+    let mut time_limit = Option::<Duration>::None;
+    let mut use_cache = true;
+    let mut profile_name = env::var(PROFILE_ENV).ok().filter(|name| !name.is_empty());
+    let mut positional = Vec::<&str>::new();
+
+    let mut args = args.iter().peekable();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--all" => all = true,
+            "--no-cache" => use_cache = false,
+            "--profile" => profile_name = Some(option_value(&mut args, "--profile")?.to_owned()),
+            "--input" => {
+                input_file = Some(match option_value(&mut args, "--input")? {
+                    "-" => InputSource::Stdin,
+                    path => InputSource::File(PathBuf::from(path)),
+                });
+            }
+            "--inputs-dir" => inputs_dir = Some(PathBuf::from(option_value(&mut args, "--inputs-dir")?)),
+            "--sample" => {
+                // the sample number is optional, so only consume the next argument if it is one
+                let number = args.next_if(|value| value.parse::<u8>().is_ok());
+
+                let number = match number {
+                    Some(number) => parse_number::<u8>(number, "sample")?,
+                    None => 1,
+                };
+
+                if number == 0 {
+                    return Err(UsageError(String::from("--sample must be at least 1, samples are numbered from 1")));
+                }
+
+                sample = Some(number);
+            }
+            "--format" => {
+                let name = option_value(&mut args, "--format")?;
+
+                format = Format::from_name(name)
+                    .ok_or_else(|| UsageError(format!("unknown format '{}', expected text, json or csv", name)))?;
+            }
+            "--jobs" => {
+                let count = parse_number::<usize>(option_value(&mut args, "--jobs")?, "job count")?;
+
+                if count == 0 {
+                    return Err(UsageError(String::from("--jobs must be at least 1")));
+                }
+
+                jobs = Some(count);
+            }
+            "--time-limit" => {
+                let seconds = parse_number::<f64>(option_value(&mut args, "--time-limit")?, "time limit")?;
+
+                time_limit = Some(
+                    Duration::try_from_secs_f64(seconds)
+                        .ok()
+                        .filter(|limit| !limit.is_zero())
+                        .ok_or_else(|| UsageError(String::from("--time-limit must be a positive number of seconds")))?,
+                );
+            }
+            flag if flag.starts_with('-') => {
+                return Err(UsageError(format!("unknown option '{}'", flag)));
+            }
+            value => positional.push(value),
+        }
+    }
+
+    let target = match command {
+        // verify checks the whole calendar unless told otherwise
+        "verify" if !all && positional.is_empty() => RunTarget::All,
+        _ => parse_run_target(all, &positional, command)?,
+    };
+
+    if sample.is_some() && (input_file.is_some() || inputs_dir.is_some()) {
+        return Err(UsageError(String::from("--sample cannot be combined with --input or --inputs-dir")));
+    }
+
+    let profile = profile_name.map(|name| Profile::new(&name).map_err(UsageError)).transpose()?;
+
+    let input = match (input_file, inputs_dir) {
+        (Some(_), Some(_)) => {
+            return Err(UsageError(String::from("--input cannot be combined with --inputs-dir")));
+        }
+        (Some(_), None) if target == RunTarget::All => {
+            return Err(UsageError(String::from("--input needs a single day, not --all")));
+        }
+        (Some(source), None) => source,
+        (None, Some(dir)) => InputSource::Directory(dir),
+        (None, None) => match &profile {
+            Some(profile) => InputSource::Directory(profile.inputs_dir()),
+            None => InputSource::default(),
+        },
+    };
+
+    return Ok(RunOptions {
+        target,
+        input,
+        sample,
+        format,
+        jobs,
+        time_limit,
+        use_cache,
+        profile,
+    });
+}
+
+fn parse_run_target(all: bool, positional: &[&str], command: &str) -> Result<RunTarget, UsageError> {
+    if all {
+        if !positional.is_empty() {
+            return Err(UsageError(String::from("--all cannot be combined with a day or part")));
+        }
+
+        return Ok(RunTarget::All);
+    }
+
+    return match positional {
+        [] => Err(UsageError(String::from("expected a day or --all"))),
+        [day] => Ok(RunTarget::Day { day: parse_number(day, "day")?, part: None }),
+        [day, part] => Ok(RunTarget::Day {
+            day: parse_number(day, "day")?,
+            part: Some(parse_number(part, "part")?),
+        }),
+        _ => Err(UsageError(format!("too many arguments for '{}'", command))),
+    };
+}
+
+fn parse_bench(args: &[String]) -> Result<BenchOptions, UsageError> {
+    let mut settings = BenchSettings { warmup: 3, iterations: 10, threshold: 10.0 };
+    let mut baseline = default_baseline_path();
+    let mut save_baseline = false;
+    let mut rest = Vec::<String>::new();
+
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--warmup" => settings.warmup = parse_number(option_value(&mut args, "--warmup")?, "warm-up count")?,
+            "--iterations" => {
+                settings.iterations = parse_number(option_value(&mut args, "--iterations")?, "iteration count")?;
+            }
+            "--threshold" => {
+                settings.threshold = parse_number(option_value(&mut args, "--threshold")?, "threshold")?;
+            }
+            "--baseline" => baseline = PathBuf::from(option_value(&mut args, "--baseline")?),
+            "--save-baseline" => save_baseline = true,
+            // everything else selects the day and its input, just like for 'run'
+            _ => rest.push(arg.clone()),
+        }
+    }
+
+    if settings.iterations == 0 {
+        return Err(UsageError(String::from("--iterations must be at least 1")));
+    }
+
+    if settings.threshold.is_nan() || settings.threshold < 0.0 {
+        return Err(UsageError(String::from("--threshold must be a non-negative percentage")));
+    }
+
+    let options = parse_run(&rest, "bench")?;
+
+    let run_only = options.sample.is_some()
+        || options.jobs.is_some()
+        || options.time_limit.is_some()
+        || options.format != Format::Text
+        || !options.use_cache;
+
+    if run_only {
+        return Err(UsageError(String::from(
+            "--sample, --jobs, --time-limit, --format and --no-cache cannot be used with 'bench'",
+        )));
+    }
+
+    let RunTarget::Day { day, part } = options.target else {
+        return Err(UsageError(String::from("'bench' needs a single day, not --all")));
+    };
+
+    return Ok(BenchOptions { day, part, input: options.input, settings, baseline, save_baseline });
+}
+
+fn parse_batch(args: &[String]) -> Result<BatchOptions, UsageError> {
+    // the directory follows the day and the optional part, ahead of any options
+    let positional = args.iter().take_while(|arg| !arg.starts_with('-')).count();
+
+    let (target, dir) = match positional {
+        2 => (&args[..1], &args[1]),
+        3 => (&args[..2], &args[2]),
+        _ => return Err(UsageError(String::from("'batch' expects a day, an optional part and a directory"))),
+    };
+
+    if args.iter().any(|arg| ["--all", "--sample", "--input", "--inputs-dir", "--format"].contains(&arg.as_str())) {
+        return Err(UsageError(String::from(
+            "--all, --sample, --input, --inputs-dir and --format cannot be used with 'batch'",
+        )));
+    }
+
+    let rest = target.iter().chain(&args[positional..]).cloned().collect::<Vec<_>>();
+    let run = parse_run(&rest, "batch")?;
+
+    return Ok(BatchOptions { dir: PathBuf::from(dir), run });
+}
+
+/// Parses the arguments following the binary name, along with the logging level, whose
+/// flags can appear anywhere
+pub fn parse_args(args: &[String]) -> Result<(Command, Level), UsageError> {
+    let mut level = Option::<Level>::None;
+    let mut rest = Vec::<String>::new();
+
+    for arg in args {
+        let flag_level = match arg.as_str() {
+            "--quiet" | "-q" => Level::Quiet,
+            "--verbose" | "-v" => Level::Verbose,
+            "--trace" => Level::Trace,
+            _ => {
+                rest.push(arg.clone());
+                continue;
+            }
+        };
+
+        if level.is_some_and(|level| level != flag_level) {
+            return Err(UsageError(String::from("--quiet, --verbose and --trace cannot be combined")));
+        }
+
+        level = Some(flag_level);
+    }
+
+    return Ok((parse_command(&rest)?, level.unwrap_or_default()));
+}
+
+fn parse_command(args: &[String]) -> Result<Command, UsageError> {
+    return match args.split_first() {
+        None => Ok(Command::Help),
+        Some((command, rest)) => match command.as_str() {
+            "run" => Ok(Command::Run(parse_run(rest, "run")?)),
+            "verify" => Ok(Command::Verify(parse_run(rest, "verify")?)),
+            "bench" => Ok(Command::Bench(parse_bench(rest)?)),
+            "batch" => Ok(Command::Batch(parse_batch(rest)?)),
+            "new" => match rest {
+                [day] => match parse_number(day, "day")? {
+                    day @ 1..=25 => Ok(Command::New { day }),
+                    day => Err(UsageError(format!("day {} is not in the calendar", day))),
+                },
+                _ => Err(UsageError(String::from("'new' expects a single day"))),
+            },
+            "lint" => match rest {
+                [day, file] => Ok(Command::Lint {
+                    day: parse_number(day, "day")?,
+                    input: match file.as_str() {
+                        "-" => InputSource::Stdin,
+                        path => InputSource::File(PathBuf::from(path)),
+                    },
+                }),
+                _ => Err(UsageError(String::from("'lint' expects a day and an input file"))),
+            },
+            "help" | "-h" | "--help" => Ok(Command::Help),
+            other => Err(UsageError(format!("unknown command '{}'", other))),
+        },
+    };
+}
+
+const USAGE: &str = "\
+Usage:
+  aoc run <day> [part] [options]   run one day, or a single part of it
+  aoc run --all [options]          run every day and part
+  aoc verify [day] [part] [options]
+                                   check answers against answers.txt, reporting pass,
+                                   fail or unknown for each part (default: every day)
+  aoc bench <day> [part] [options]
+                                   time a day over several runs and compare the timings
+                                   with the saved baseline
+  aoc batch <day> [part] <dir> [options]
+                                   run a day against every input file in <dir> and
+                                   tabulate the answers, timings and failures
+  aoc lint <day> <file>            check an input against the day's format without solving
+                                   it, reporting every problem with its line and column
+  aoc new <day>                    generate the files for a new day and register it
+  aoc help                         show this message
+
+Options:
+  --input <file>        read the puzzle input from <file>, or from stdin when <file> is '-'
+  --inputs-dir <dir>    read inputs from <dir>/dayNN.txt or <dir>/day<N>/input.txt
+                        (default: $AOC_INPUTS_DIR, else ~/.config/aoc/inputs/2023 for
+                        the days it has, then inputs/ in the crate directory)
+  --sample [n]          run against the n-th example from the puzzle text (default 1)
+                        instead of the puzzle input
+  -q, --quiet           print only the answers, without summaries
+  -v, --verbose         also print a few lines to stderr about how each part was solved
+  --trace               also print every item of the input to stderr as it is processed
+  --jobs <n>            solve days and parts concurrently on <n> threads, which days that
+                        use threads themselves share; results are still printed in order
+  --time-limit <secs>   stop any part that runs for longer than this and report it as
+                        timed out (solvers check for it in their hot loops)
+  --format <format>     text (default), or json / csv for one record per part with the
+                        day, part, sample, answer, expected answer, status and timings
+  --profile <name>      use the inputs and known answers of profile <name>, kept in
+                        ~/.config/aoc/profiles/<name> (default: $AOC_PROFILE)
+  --no-cache            solve every part again instead of reusing the answers cached
+                        for the same input and solution version
+
+Bench options:
+  --warmup <n>          untimed runs before measuring (default 3)
+  --iterations <n>      timed runs (default 10)
+  --threshold <pct>     slowdown of the median over the baseline that counts as a
+                        regression (default 10)
+  --baseline <file>     baseline to compare with (default: bench-baseline.txt in the crate)
+  --save-baseline       replace the day's baseline with this run; a day without a baseline
+                        always saves one
+";
+
+/// Builds the usage text, listing every day and the parts it has
+pub fn usage(calendar: &[(u8, Vec<u8>)]) -> String {
+    let mut text = format!("{}\nAvailable days:\n", USAGE);
+
+    for (day, parts) in calendar {
+        let parts = parts.iter().map(|part| part.to_string()).collect::<Vec<_>>();
+
+        text.push_str(&format!("  day {:>2}: part {}\n", day, parts.join(", ")));
+    }
+
+    return text;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    use super::{parse_args, parse_batch, parse_run, Command, RunTarget};
+    use crate::input::InputSource;
+    use crate::logging::Level;
+
+    fn args(line: &str) -> Vec<String> {
+        return line.split_whitespace().map(String::from).collect();
+    }
+
+    #[test]
+    fn run_selects_a_day_a_part_or_everything() {
+        assert_eq!(parse_run(&args("8"), "run").unwrap().target, RunTarget::Day { day: 8, part: None });
+        assert_eq!(parse_run(&args("8 2"), "run").unwrap().target, RunTarget::Day { day: 8, part: Some(2) });
+        assert_eq!(parse_run(&args("--all"), "run").unwrap().target, RunTarget::All);
+        assert_eq!(parse_run(&args(""), "verify").unwrap().target, RunTarget::All);
+
+        assert!(parse_run(&args(""), "run").is_err());
+        assert!(parse_run(&args("8 2 1"), "run").is_err());
+        assert!(parse_run(&args("8 --all"), "run").is_err());
+        assert!(parse_run(&args("eight"), "run").is_err());
+    }
+
+    #[test]
+    fn sample_number_is_optional() {
+        assert_eq!(parse_run(&args("8 --sample"), "run").unwrap().sample, Some(1));
+        assert_eq!(parse_run(&args("8 2 --sample 2"), "run").unwrap().sample, Some(2));
+
+        let options = parse_run(&args("--sample 2 8"), "run").unwrap();
+        assert_eq!(options.sample, Some(2));
+        assert_eq!(options.target, RunTarget::Day { day: 8, part: None });
+
+        assert!(parse_run(&args("8 --sample 0"), "run").is_err());
+        assert!(parse_run(&args("8 --sample --input day08.txt"), "run").is_err());
+    }
+
+    #[test]
+    fn input_is_a_file_or_stdin() {
+        let options = parse_run(&args("8 --input day08.txt"), "run").unwrap();
+        assert_eq!(options.input, InputSource::File(PathBuf::from("day08.txt")));
+
+        let options = parse_run(&args("8 --input -"), "run").unwrap();
+        assert_eq!(options.input, InputSource::Stdin);
+
+        let options = parse_run(&args("8 --inputs-dir inputs"), "run").unwrap();
+        assert_eq!(options.input, InputSource::Directory(PathBuf::from("inputs")));
+
+        assert!(parse_run(&args("8 --input"), "run").is_err());
+        assert!(parse_run(&args("--all --input day08.txt"), "run").is_err());
+        assert!(parse_run(&args("8 --input day08.txt --inputs-dir inputs"), "run").is_err());
+    }
+
+    #[test]
+    fn jobs_must_be_a_positive_count() {
+        assert_eq!(parse_run(&args("--all --jobs 4"), "run").unwrap().jobs, Some(4));
+        assert_eq!(parse_run(&args("--all"), "run").unwrap().jobs, None);
+
+        assert!(parse_run(&args("--all --jobs 0"), "run").is_err());
+        assert!(parse_run(&args("--all --jobs many"), "run").is_err());
+        assert!(parse_run(&args("--all --jobs"), "run").is_err());
+    }
+
+    #[test]
+    fn time_limit_is_a_positive_number_of_seconds() {
+        let options = parse_run(&args("--all --time-limit 1.5"), "run").unwrap();
+        assert_eq!(options.time_limit, Some(Duration::from_millis(1500)));
+
+        assert!(parse_run(&args("--all --time-limit 0"), "run").is_err());
+        assert!(parse_run(&args("--all --time-limit -1"), "run").is_err());
+        assert!(parse_run(&args("--all --time-limit soon"), "run").is_err());
+    }
+
+    #[test]
+    fn unknown_options_are_errors() {
+        let error = parse_run(&args("8 --fast"), "run").unwrap_err();
+
+        assert_eq!(error.0, "unknown option '--fast'");
+        assert!(parse_args(&args("run 8 -x")).is_err());
+    }
+
+    #[test]
+    fn logging_flags_can_go_anywhere() {
+        let (command, level) = parse_args(&args("-q run 8 2")).unwrap();
+        assert!(matches!(command, Command::Run(options) if options.target == RunTarget::Day { day: 8, part: Some(2) }));
+        assert_eq!(level, Level::Quiet);
+
+        let (_, level) = parse_args(&args("run 8 --verbose")).unwrap();
+        assert_eq!(level, Level::Verbose);
+
+        assert!(parse_args(&args("run 8 --quiet --verbose")).is_err());
+    }
+
+    #[test]
+    fn batch_takes_the_last_positional_as_the_directory() {
+        let options = parse_batch(&args("8 inputs/day8")).unwrap();
+
+        assert_eq!(options.dir, PathBuf::from("inputs/day8"));
+        assert_eq!(options.run.target, RunTarget::Day { day: 8, part: None });
+    }
+
+    #[test]
+    fn batch_accepts_a_part_and_options_after_the_directory() {
+        let options = parse_batch(&args("8 2 inputs --jobs 4 --no-cache")).unwrap();
+
+        assert_eq!(options.dir, PathBuf::from("inputs"));
+        assert_eq!(options.run.target, RunTarget::Day { day: 8, part: Some(2) });
+        assert_eq!(options.run.jobs, Some(4));
+        assert!(!options.run.use_cache);
+    }
+
+    #[test]
+    fn batch_rejects_a_missing_or_extra_positional() {
+        assert!(parse_batch(&args("8")).is_err());
+        assert!(parse_batch(&args("inputs --jobs 4")).is_err());
+        assert!(parse_batch(&args("8 2 inputs extra")).is_err());
+    }
+
+    #[test]
+    fn batch_rejects_options_that_pick_other_inputs() {
+        assert!(parse_batch(&args("8 inputs --sample")).is_err());
+        assert!(parse_batch(&args("8 inputs --input day08.txt")).is_err());
+        assert!(parse_batch(&args("8 inputs --all")).is_err());
+    }
+}
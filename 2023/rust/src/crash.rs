@@ -0,0 +1,121 @@
+use std::any::Any;
+use std::backtrace::{Backtrace, BacktraceStatus};
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, Once};
+
+/// A panic caught at a part's boundary
+#[derive(Debug, Clone, PartialEq)]
+pub struct Crash {
+    pub message: String,
+    /// `file:line:column` of the panic, when the panic hook saw it
+    pub location: Option<String>,
+    /// Only captured when enabled through `RUST_BACKTRACE`
+    pub backtrace: Option<String>,
+}
+
+impl fmt::Display for Crash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.location {
+            Some(location) => write!(f, "{} at {}", self.message, location),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+/// What the panic hook saw of the last panic
+struct PanicRecord {
+    message: String,
+    location: Option<String>,
+    backtrace: Option<String>,
+}
+
+static INSTALL_HOOK: Once = Once::new();
+static LAST_PANIC: Mutex<Option<PanicRecord>> = Mutex::new(None);
+/// Number of boundaries currently running, while which panics are not printed
+static ACTIVE: AtomicUsize = AtomicUsize::new(0);
+
+fn payload_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        return message.to_string();
+    }
+
+    if let Some(message) = payload.downcast_ref::<String>() {
+        return message.clone();
+    }
+
+    return String::from("Box<dyn Any>");
+}
+
+/// Records every panic for [`catch`] to pick up. The hook runs on the panicking thread,
+/// which for a solver that uses rayon is not the thread that catches the panic, so the
+/// record is shared between threads rather than kept per thread.
+fn install_hook() {
+    INSTALL_HOOK.call_once(|| {
+        let default_hook = panic::take_hook();
+
+        panic::set_hook(Box::new(move |info| {
+            if ACTIVE.load(Ordering::SeqCst) == 0 {
+                return default_hook(info);
+            }
+
+            let backtrace = Backtrace::capture();
+
+            let record = PanicRecord {
+                message: payload_message(info.payload()),
+                location: info.location().map(|location| location.to_string()),
+                backtrace: (backtrace.status() == BacktraceStatus::Captured).then(|| backtrace.to_string()),
+            };
+
+            if let Ok(mut last_panic) = LAST_PANIC.lock() {
+                *last_panic = Some(record);
+            }
+        }));
+    });
+}
+
+/// Runs `solve` behind a panic boundary, turning a panic into a [`Crash`] instead of
+/// taking the whole run down. Panics inside the boundary are not printed.
+pub fn catch<T>(solve: impl FnOnce() -> T) -> Result<T, Crash> {
+    install_hook();
+
+    ACTIVE.fetch_add(1, Ordering::SeqCst);
+    let result = panic::catch_unwind(AssertUnwindSafe(solve));
+    ACTIVE.fetch_sub(1, Ordering::SeqCst);
+
+    let payload = match result {
+        Ok(value) => return Ok(value),
+        Err(payload) => payload,
+    };
+
+    let message = payload_message(payload.as_ref());
+
+    // with parts running in parallel the last panic may belong to another part, in which
+    // case only the message is known
+    let record = LAST_PANIC
+        .lock()
+        .ok()
+        .and_then(|mut last_panic| last_panic.take_if(|record| record.message == message));
+
+    return Err(match record {
+        Some(record) => Crash { message, location: record.location, backtrace: record.backtrace },
+        None => Crash { message, location: None, backtrace: None },
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::catch;
+
+    #[test]
+    fn panic_is_caught_with_where_it_happened() {
+        let crash = catch(|| -> u32 { panic!("no galaxies in {}", "row 3") }).unwrap_err();
+
+        assert_eq!(crash.message, "no galaxies in row 3");
+        assert!(crash.location.as_deref().is_some_and(|location| location.starts_with("src/crash.rs:")), "{:?}", crash);
+
+        // the boundary is left in a usable state
+        assert_eq!(catch(|| 46), Ok(46));
+    }
+}
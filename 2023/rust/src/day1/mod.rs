@@ -1,3 +1,43 @@
+use crate::error::Result;
+use crate::solution::{solve_input, Answer, Sample, Solution};
+
 pub mod part1;
 pub mod part2;
 pub mod utils;
+
+pub struct Day1;
+
+/// Parses the puzzle input and solves part 1 of it
+pub fn part1(input: &str) -> Result<Answer> {
+    return solve_input(&Day1, 1, input);
+}
+
+/// Parses the puzzle input and solves part 2 of it
+pub fn part2(input: &str) -> Result<Answer> {
+    return solve_input(&Day1, 2, input);
+}
+
+impl Solution for Day1 {
+    type Input = Vec<String>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    const DAY: u8 = 1;
+
+    const SAMPLES: &'static [Sample] = &[
+        Sample { part: 1, file: "input_sample.txt" },
+        Sample { part: 2, file: "input_simple.txt" },
+    ];
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        return Ok(input.lines().map(|line| line.to_owned()).collect());
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Part1> {
+        return part1::solve(input);
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Part2> {
+        return part2::solve(input);
+    }
+}
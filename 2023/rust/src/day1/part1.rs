@@ -1,6 +1,5 @@
-use std::path::Path;
+use crate::error::{Error, Result};
 
-use crate::io_utils::read_lines;
 use super::utils::vec_char_to_calibration_value;
 
 /*
@@ -31,42 +30,29 @@ In this example, the calibration values of these four lines are 12, 38, 15, and
 Consider your entire calibration document. What is the sum of all of the calibration values?
 */
 
-const EXPECTED_ANSWER: usize = 54968;
-
-fn extract_calibation_value_from_line(line: &String) -> usize {
+fn extract_calibation_value_from_line(line_ix: usize, line: &String) -> Result<usize> {
     let mut calibration_chars = Vec::<char>::new();
 
     // TODO: would be nicer to ony build the chars vec up to max of 2 in size
     for char in line.chars() {
-        if char.is_numeric() {
+        if char.is_ascii_digit() {
             calibration_chars.push(char);
         }
     }
 
-    return vec_char_to_calibration_value(&calibration_chars);
+    if calibration_chars.is_empty() {
+        return Err(Error::on_line(line_ix, line, "Line has no digits"));
+    }
+
+    return Ok(vec_char_to_calibration_value(&calibration_chars));
 }
 
-pub fn run() {
+pub fn solve(lines: &Vec<String>) -> Result<usize> {
     let mut calibration_sum = 0;
 
-    let lines_result = read_lines(Path::new("src/day1/input.txt"));
-
-    if let Ok(lines) = lines_result {
-        for line in lines {
-            if let Ok(ip) = line {
-                calibration_sum += extract_calibation_value_from_line(&ip);
-            }
-        }
+    for (line_ix, line) in lines.iter().enumerate() {
+        calibration_sum += extract_calibation_value_from_line(line_ix, line)?;
     }
 
-    assert_eq!(
-        calibration_sum, EXPECTED_ANSWER,
-        "expected {}, got {}",
-        EXPECTED_ANSWER, calibration_sum
-    );
-
-    println!(
-        "Part 1 -> Total calibration sum of all string lines: {}",
-        calibration_sum
-    );
+    return Ok(calibration_sum);
 }
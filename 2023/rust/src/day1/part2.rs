@@ -1,8 +1,7 @@
-use std::path::Path;
-
 use phf::phf_map;
 
-use crate::io_utils::read_lines;
+use crate::error::{Error, Result};
+
 use super::utils::vec_char_to_calibration_value;
 
 /*
@@ -39,14 +38,12 @@ static WORD_TO_NUMBER_MAP: phf::Map<&'static str, char> = phf_map! {
     "nine" => '9',
 };
 
-const EXPECTED_ANSWER: usize = 54094;
-
-fn extract_calibation_value_from_line(line: &String) -> usize {
+fn extract_calibation_value_from_line(line_ix: usize, line: &String) -> Result<usize> {
     let mut calibration_chars = Vec::<char>::new();
     let mut word_char_str = String::from("");
 
     for char in line.chars() {
-        if char.is_numeric() {
+        if char.is_ascii_digit() {
             word_char_str.clear();
             calibration_chars.push(char);
             continue;
@@ -66,46 +63,33 @@ fn extract_calibation_value_from_line(line: &String) -> usize {
                 let last_char = word_char_str[last_ix..=last_ix].to_string();
 
                 word_char_str.clear();
-                word_char_str.push(last_char.chars().nth(0).unwrap());
+                word_char_str.push(last_char.chars().next().unwrap());
 
                 break;
             }
         }
     }
 
+    if calibration_chars.is_empty() {
+        return Err(Error::on_line(line_ix, line, "Line has no digits or spelled out digits"));
+    }
+
     let calibration_value = vec_char_to_calibration_value(&calibration_chars);
 
-    println!(
+    trace!(
         "{} -> {:?} -> {}",
         line, &calibration_chars, &calibration_value
     );
 
-    return calibration_value;
+    return Ok(calibration_value);
 }
 
-pub fn run() {
+pub fn solve(lines: &Vec<String>) -> Result<usize> {
     let mut calibration_sum = 0;
 
-    let lines_result = read_lines(Path::new("src/day1/input.txt"));
-
-    if let Ok(lines) = lines_result {
-        for line in lines {
-            if let Ok(ip) = line {
-                calibration_sum += extract_calibation_value_from_line(&ip);
-            }
-        }
+    for (line_ix, line) in lines.iter().enumerate() {
+        calibration_sum += extract_calibation_value_from_line(line_ix, line)?;
     }
 
-    assert_eq!(
-        calibration_sum,
-        EXPECTED_ANSWER.to_owned(),
-        "expected {}, got {}",
-        EXPECTED_ANSWER.to_owned(),
-        calibration_sum
-    );
-
-    println!(
-        "Part 2 -> Total calibration sum of all string lines: {}",
-        calibration_sum
-    );
+    return Ok(calibration_sum);
 }
@@ -3,11 +3,11 @@ pub fn char_digit_to_digit(char_digit: &char) -> usize {
 }
 
 pub fn digit_parts_to_two_digit_number(digit_one: usize, digit_two: Option<usize>) -> usize {
-    return String::from(format!(
+    return format!(
         "{}{}",
         digit_one,
-        digit_two.or(Some(digit_one)).unwrap()
-    ))
+        digit_two.unwrap_or(digit_one)
+    )
     .parse()
     .unwrap();
 }
@@ -20,25 +20,25 @@ pub fn chars_to_two_digit_number(digit_one: &char, digit_two: Option<&char>) ->
 }
 
 pub fn vec_char_to_calibration_value(calibration_chars: &Vec<char>) -> usize {
-    if calibration_chars.len() == 0 {
+    if calibration_chars.is_empty() {
         return 0;
     }
 
     if calibration_chars.len() == 1 {
-        let the_digit = calibration_chars.get(0).unwrap();
+        let the_digit = calibration_chars.first().unwrap();
 
         return chars_to_two_digit_number(the_digit, Some(the_digit));
     }
 
     if calibration_chars.len() == 2 {
         return chars_to_two_digit_number(
-            calibration_chars.get(0).unwrap(),
+            calibration_chars.first().unwrap(),
             Some(calibration_chars.get(1).unwrap()),
         );
     }
 
     return chars_to_two_digit_number(
-        calibration_chars.get(0).unwrap(),
-        Some(calibration_chars.get(calibration_chars.len() - 1).unwrap()),
+        calibration_chars.first().unwrap(),
+        Some(calibration_chars.last().unwrap()),
     );
 }
@@ -1,4 +1,50 @@
+use crate::error::{Error, Result};
+use crate::solution::{solve_input, Answer, Sample, Solution};
+
 pub mod part1;
 pub mod part2;
 pub mod utils;
-pub mod defs;
\ No newline at end of file
+pub mod defs;
+
+pub struct Day10;
+
+/// Parses the puzzle input and solves part 1 of it
+pub fn part1(input: &str) -> Result<Answer> {
+    return solve_input(&Day10, 1, input);
+}
+
+/// Parses the puzzle input and solves part 2 of it
+pub fn part2(input: &str) -> Result<Answer> {
+    return solve_input(&Day10, 2, input);
+}
+
+impl Solution for Day10 {
+    type Input = defs::GridState;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    const DAY: u8 = 10;
+
+    const SAMPLES: &'static [Sample] = &[
+        Sample { part: 1, file: "input_sample.txt" },
+        Sample { part: 1, file: "input_sample_2.txt" },
+        Sample { part: 2, file: "input_sample_part2_1.txt" },
+        Sample { part: 2, file: "input_sample_part2_2.txt" },
+    ];
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        return utils::build(input);
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Part1> {
+        return part1::solve(input);
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Part2> {
+        return part2::solve(input);
+    }
+
+    fn lint(&self, input: &str) -> Vec<Error> {
+        return utils::lint(input);
+    }
+}
@@ -106,12 +106,11 @@ Find the single giant loop starting at S. How many steps along the loop does it
 
 */
 
-use crate::day10::utils::build_loop_chain;
+use crate::day10::{defs::GridState, utils::build_loop_chain};
+use crate::error::Result;
 
-const EXPECTED_ANSWER: i32 = 6846;
+pub fn solve(state: &GridState) -> Result<usize> {
+  let loop_chain = build_loop_chain(state)?;
 
-pub fn run() {
-  let loop_chain = build_loop_chain();
-
-  println!("{}", loop_chain.len() / 2);
+  return Ok(loop_chain.len() / 2);
 }
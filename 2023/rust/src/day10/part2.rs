@@ -101,12 +101,10 @@ Figure out whether you have time to search for the nest by calculating the area
 
 */
 
-use crate::{day10::utils::build_loop_chain, math::{picks_theorem_inner_points, shoelace}};
+use crate::{day10::{defs::GridState, utils::build_loop_chain}, error::Result, math::{picks_theorem_inner_points, shoelace}};
 
-const EXPECTED_ANSWER: f64 = 325.0;
-
-pub fn run() {
-    let loop_chain = build_loop_chain();
+pub fn solve(state: &GridState) -> Result<usize> {
+    let loop_chain = build_loop_chain(state)?;
 
     let xy_points = loop_chain
         .iter()
@@ -116,7 +114,6 @@ pub fn run() {
     let area = shoelace(&xy_points);
     let inner_points = picks_theorem_inner_points(area, xy_points.len(), 0);
 
-    assert_eq!(EXPECTED_ANSWER, inner_points);
-
-    println!("inner pts: {}", inner_points);
+    // the loop runs along whole tiles, so the count only strays from a whole number by rounding
+    return Ok(inner_points.round() as usize);
 }
@@ -1,6 +1,9 @@
 use std::collections::{HashMap, HashSet};
 
-use crate::io_utils::read_lines_fully;
+
+use crate::cancel;
+use crate::error::{Error, Result};
+use crate::io_utils::{check_grid, grid_errors};
 
 use super::defs::{FromToNextDirectionState, PotentialGridLocation, Direction, MoveDirectionCombo, FromDirection, Tile, LocationMeta, MoveMappings, StartingPoint, GridState, LineIndex};
 
@@ -189,89 +192,98 @@ pub fn get_grid_item<'a>(
     return grid
         .1
         .get(&point.0)
-        .and_then(|line| line.iter().nth(point.1 as usize));
+        .and_then(|line| line.get(point.1 as usize));
 }
 
 pub const fn is_point_within_grid(point: &PotentialGridLocation) -> bool {
     return point.0 >= 0 && point.1 >= 0;
 }
 
-const INPUT: &'static str = include_str!("input.txt");
-const SAMPLE: &'static str = include_str!("input_sample.txt");
+fn error_at_point(point: &PotentialGridLocation, message: &str) -> Error {
+    return Error {
+        line: (point.0 >= 0).then(|| point.0 as usize + 1),
+        column: (point.1 >= 0).then(|| point.1 as usize + 1),
+        ..Error::new(message)
+    };
+}
+
+pub fn build(input: &str) -> Result<GridState> {
+    check_grid(input)?;
 
-pub fn build() -> GridState {
-    let mut starting_point_location: StartingPoint = (0, 0);
+    let mut starting_point_location = None::<StartingPoint>;
     let valid_move_map = build_valid_move_map();
     let direction_map = build_directional_move_map();
     let mut tile_grid = HashMap::<LineIndex, Vec<Tile>>::new();
 
-    INPUT
-        .lines()
-        .enumerate()
-        .for_each(|(line_ix, line)| {
-            let mut line_tiles = Vec::<Tile>::with_capacity(line.len());
-            for (char_ix, char) in line.chars().enumerate() {
-                let tile = match char {
-                    '|' => Tile::VerticalPipe,
-                    '-' => Tile::HorizontalPipe,
-                    'L' => Tile::NorthEastRightAngleBend,
-                    'J' => Tile::NorthWestRightAngleBend,
-                    '7' => Tile::SouthWestRightAngleBend,
-                    'F' => Tile::SouthEastRightAngleBend,
-                    '.' => Tile::Ground,
-                    'S' => {
-                        // need to record starting location
-                        starting_point_location = (line_ix as isize, char_ix as isize);
-
-                        Tile::StartingPoint
+    for (line_ix, line) in input.lines().enumerate() {
+        let mut line_tiles = Vec::<Tile>::with_capacity(line.len());
+        for (char_ix, (byte_ix, char)) in line.char_indices().enumerate() {
+            let tile = match char {
+                '|' => Tile::VerticalPipe,
+                '-' => Tile::HorizontalPipe,
+                'L' => Tile::NorthEastRightAngleBend,
+                'J' => Tile::NorthWestRightAngleBend,
+                '7' => Tile::SouthWestRightAngleBend,
+                'F' => Tile::SouthEastRightAngleBend,
+                '.' => Tile::Ground,
+                'S' => {
+                    if starting_point_location.is_some() {
+                        return Err(Error::at(line_ix, line, &line[byte_ix..=byte_ix], "Grid has more than one starting point"));
                     }
-                    _ => Tile::Unknown,
-                };
 
-                line_tiles.push(tile);
-            }
+                    // need to record starting location
+                    starting_point_location = Some((line_ix as isize, char_ix as isize));
+
+                    Tile::StartingPoint
+                }
+                // the sketches in the puzzle text mark tiles with I and O, which are not pipes
+                _ => Tile::Unknown,
+            };
 
-            tile_grid.insert(line_ix as isize, line_tiles);
-        });
+            line_tiles.push(tile);
+        }
 
-    return (starting_point_location, tile_grid, valid_move_map, direction_map);
+        tile_grid.insert(line_ix as isize, line_tiles);
+    }
+
+    let starting_point_location = starting_point_location.ok_or_else(|| Error::new("Grid has no starting point S"))?;
+
+    return Ok((starting_point_location, tile_grid, valid_move_map, direction_map));
 }
 
-pub fn build_loop_chain() -> Vec::<LocationMeta> {
-    let state = build();
-  
+pub fn build_loop_chain(state: &GridState) -> Result<Vec::<LocationMeta>> {
     // figure out the loop direction we can go first
     // then once we have a direction, we can start the move loop
     let starting_point = state.0;
     let points: [(FromDirection, (isize, isize)); 4] = [
         (
             FromDirection::South,
-            (starting_point.0 as isize - 1, starting_point.1 as isize),
+            (starting_point.0 - 1, starting_point.1),
         ),
         (
             FromDirection::North,
-            (starting_point.0 as isize + 1, starting_point.1 as isize),
+            (starting_point.0 + 1, starting_point.1),
         ),
         (
             FromDirection::West,
-            (starting_point.0 as isize, starting_point.1 as isize + 1),
+            (starting_point.0, starting_point.1 + 1),
         ),
         (
             FromDirection::East,
-            (starting_point.0 as isize, starting_point.1 as isize - 1),
+            (starting_point.0, starting_point.1 - 1),
         ),
     ];
   
     let current_point = points
         .iter()
         .find(|next_point| {
-            let item = get_grid_item(&next_point.1, &state);
+            let item = get_grid_item(&next_point.1, state);
   
             if let Some(item) = item {
                 // direction you're coming + the tile you're on now
                 let next_moves = state
                     .2
-                    .get(&MoveDirectionCombo(next_point.0.clone(), item.clone()));
+                    .get(&MoveDirectionCombo(next_point.0, *item));
   
                 if let Some(next_moves) = next_moves {
                     if next_moves.contains(item) {
@@ -282,27 +294,30 @@ pub fn build_loop_chain() -> Vec::<LocationMeta> {
   
             return false;
         })
-        .unwrap();
+        .ok_or_else(|| Error::new("No pipe connects to the starting point"))?;
   
     // now that we have the effective starting point, already step of 1, we need to figure out from the point we're on now
     // which direction we can go next and check the point at next location
-    let mut looping = true;
-    let mut direction_came_from = current_point.0.clone();
-    let mut current_point_temp = current_point.1.clone();
+    let mut direction_came_from = current_point.0;
+    let mut current_point_temp = current_point.1;
   
     let mut loop_chain = Vec::<LocationMeta>::new();
+
+    let cancel = cancel::current();
     
-    while looping {
-      let current_points_tile = get_grid_item(&current_point_temp, &state).unwrap();
+    loop {
+      cancel.checkpoint();
+
+      let current_points_tile = get_grid_item(&current_point_temp, state)
+          .ok_or_else(|| error_at_point(&current_point_temp, "Loop leaves the grid"))?;
   
       loop_chain.push(LocationMeta {
           came_from: direction_came_from,
-          location: current_point_temp.clone(),
+          location: current_point_temp,
           tile: *current_points_tile,
       });
   
       if *current_points_tile == Tile::StartingPoint {
-          looping = false;
           break;
       }
   
@@ -310,9 +325,9 @@ pub fn build_loop_chain() -> Vec::<LocationMeta> {
           .3
           .get(&MoveDirectionCombo(
               direction_came_from,
-              current_points_tile.clone(),
+              *current_points_tile,
           ))
-          .unwrap();
+          .ok_or_else(|| error_at_point(&current_point_temp, "Loop is broken, the pipe does not connect"))?;
   
       let next_point = next_direction_state.calculate_next_point(&current_point_temp);
   
@@ -320,5 +335,41 @@ pub fn build_loop_chain() -> Vec::<LocationMeta> {
       current_point_temp = next_point;
     }
 
-    return loop_chain;
-}
\ No newline at end of file
+    return Ok(loop_chain);
+}
+
+/// Checks the grid is rectangular and has exactly one starting point
+pub fn lint(input: &str) -> Vec<Error> {
+    let mut errors = grid_errors(input);
+
+    let starting_points = input
+        .lines()
+        .enumerate()
+        .flat_map(|(line_ix, line)| line.matches('S').map(move |start| (line_ix, line, start)))
+        .collect::<Vec<_>>();
+
+    if starting_points.is_empty() {
+        errors.push(Error::new("Grid has no starting point S"));
+    }
+
+    for (line_ix, line, start) in starting_points.into_iter().skip(1) {
+        errors.push(Error::at(line_ix, line, start, "Grid has more than one starting point"));
+    }
+
+    return errors;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::lint;
+
+    #[test]
+    fn lint_points_at_ragged_rows() {
+        let errors = lint("..F7.\n.FJ|...\nSJ.L7\n|F--\nLJ...\n");
+
+        let positions = errors.iter().map(|error| (error.line, error.column)).collect::<Vec<_>>();
+
+        // the extra cells of the long row, and the end of the short one
+        assert_eq!(positions, [(Some(2), Some(6)), (Some(4), Some(5))]);
+    }
+}
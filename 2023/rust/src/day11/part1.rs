@@ -101,33 +101,21 @@ Expand the universe, then find the length of the shortest path between every pai
 const IMAGE_CHAR_EMPTY_SPACE: char = '.';
 const IMAGE_CHAR_GALAXY: char = '#';
 
-const INPUT: &'static str = include_str!("input.txt");
-const SAMPLE: &'static str = include_str!("input_sample.txt");
-
 use std::collections::HashSet;
-use std::str::Lines;
-use std::sync::Arc;
-use std::sync::atomic::{AtomicUsize, Ordering};
 
 use rayon::prelude::*;
 
-use aoc_2023::Point;
+use crate::Point;
 use pathfinding::directed::astar::astar;
 
+use crate::cancel::{self, CancelToken};
+use crate::checked;
+use crate::error::Result;
 use crate::path_finding::{find_all_unique_start_goal_points, StartGoal};
+use crate::progress::Progress;
 
-fn expand_galaxy(lines: Lines) -> Vec<Vec<char>> {
-    let mut expanded_galaxy_lines = Vec::<Vec<char>>::new();
-
-    for line in lines {
-        // println!("{}", line);
-        let mut chars = Vec::<char>::with_capacity(line.len());
-        for char in line.chars() {
-            chars.push(char);
-        }
-
-        expanded_galaxy_lines.push(chars);
-    }
+fn expand_galaxy(image: &Vec<Vec<char>>) -> Vec<Vec<char>> {
+    let mut expanded_galaxy_lines = image.clone();
 
 
     { // expand rows
@@ -203,23 +191,23 @@ fn find_galaxy_locations(galaxy: &Vec<Vec<char>>) -> Vec::<Point> {
     return points;
 }
 
-fn async_calc_chunks(id: usize, pairs: Vec<StartGoal>) -> u32 {
+fn async_calc_chunks(id: usize, pairs: Vec<StartGoal>, progress: &Progress, cancel: &CancelToken) -> Result<u32> {
     let mut chunk_total = 0;
 
     for start_goal in pairs {
+        cancel.checkpoint();
+
         let result = astar(
             &(start_goal.start.x, start_goal.start.y),
             |&(x, y)| {
-                let successor = vec![
+                vec![
                     (x + 1, y),
                     (x - 1, y),
                     (x, y + 1),
                     (x, y - 1),
                 ]
                 .into_iter()
-                .map(|p| (p, 1));
-    
-                successor
+                .map(|p| (p, 1))
             },
             |&(x, y)| (start_goal.goal.x.abs_diff(x) + start_goal.goal.y.abs_diff(y)) / 3,
             |&p| p == (start_goal.goal.x, start_goal.goal.y),
@@ -229,20 +217,22 @@ fn async_calc_chunks(id: usize, pairs: Vec<StartGoal>) -> u32 {
             // if ix == 0 ||  ix % 250 == 0 {
             //     println!("ID ({}) => ix {}: {:?} -> {:?} = {:?}", id, ix, start_goal.start, start_goal.goal, result.1);
             // }
-            chunk_total += result.1;
+            chunk_total = checked::add(chunk_total, result.1, "sum of path lengths")?;
         } else {
-            println!("ID ({}) => Issue calculating path for {:?} -> {:?} = {:?}", id, start_goal.start, start_goal.goal, result);
+            verbose!("ID ({}) => Issue calculating path for {:?} -> {:?} = {:?}", id, start_goal.start, start_goal.goal, result);
         }
+
+        progress.tick(1);
     }
 
-    return chunk_total;
+    return Ok(chunk_total);
 }
 
 fn build_processing_chunks(all: &HashSet<StartGoal>) -> Vec::<Vec<StartGoal>> {
     const CHUNK_SIZE: usize = 500;
 
     let mut chunks = Vec::<Vec<StartGoal>>::new();
-    let mut temp = Vec::<StartGoal>::with_capacity(CHUNK_SIZE as usize);
+    let mut temp = Vec::<StartGoal>::with_capacity(CHUNK_SIZE);
 
     for (ix, entry) in all.iter().enumerate() {
         temp.push(*entry);
@@ -256,41 +246,28 @@ fn build_processing_chunks(all: &HashSet<StartGoal>) -> Vec::<Vec<StartGoal>> {
     return chunks;
 }
 
-fn calc_chunks(chunks: Vec::<Vec<StartGoal>>, total_to_process: usize) -> u32 {
-    let remaining = AtomicUsize::new(total_to_process);
-
-    println!("Total remaining: {}", remaining.load(Ordering::SeqCst));
+fn calc_chunks(chunks: Vec::<Vec<StartGoal>>, total_to_process: usize) -> Result<u32> {
+    let progress = Progress::new("Day 11, part 1: shortest paths", total_to_process);
+    // rayon workers do not see this thread's token, so it is handed to them
+    let cancel = cancel::current();
 
     return chunks
         .par_iter()
         .enumerate()
-        .map(|item| {
-            let total_in_chunk = item.1.len();
-            let result = async_calc_chunks(item.0, item.1.to_vec());
-
-            remaining.fetch_sub(total_in_chunk, Ordering::SeqCst);
-
-            println!("Total remaining: {}", remaining.load(Ordering::SeqCst));
-
-            result
-        })
-        .sum::<u32>();
+        .map(|item| async_calc_chunks(item.0, item.1.to_vec(), &progress, &cancel))
+        .try_reduce(|| 0, |a, b| checked::add(a, b, "sum of path lengths"));
 }
 
-const EXPECTED_ANSWER: usize = 9_233_514;
-
 // overly complicated solution apparently... manhattan approach seems the best?
-pub fn run() {
-    let expanded = expand_galaxy(INPUT.lines());
+pub fn solve(image: &Vec<Vec<char>>) -> Result<usize> {
+    let expanded = expand_galaxy(image);
     let points = find_galaxy_locations(&expanded);
 
     let all_start_goal_pairs = find_all_unique_start_goal_points(&points);
 
     let chunks = build_processing_chunks(&all_start_goal_pairs);
 
-    let total_sum = calc_chunks(chunks, all_start_goal_pairs.len());
-
-    assert_eq!(EXPECTED_ANSWER, total_sum as usize);
+    let total_sum = calc_chunks(chunks, all_start_goal_pairs.len())?;
 
-    println!("Total sum: {}", total_sum);
+    return Ok(total_sum as usize);
 }
@@ -13,14 +13,10 @@ Starting with the same initial image, expand the universe according to these new
 
 */
 
-const EXPECTED_ANSWER: i64 = 363293506944;
-
 use std::cmp::{max, min};
 
 use crate::math::manhattan_distance;
 
-const INPUT: &'static str = include_str!("input.txt");
-
 const IMAGE_CHAR_EMPTY_SPACE: char = '.';
 const IMAGE_CHAR_GALAXY: char = '#';
 const EMPTY_ROW_OR_COL_FACTOR: i64 = 999999;
@@ -58,19 +54,7 @@ fn expand(galaxy: &Vec<Vec<char>>) -> (Vec<usize>, Vec<usize>) {
     (empty_row_indices, empty_col_indices)
 }
 
-pub fn run() {
-    let input = INPUT
-        .lines()
-        .map(|line| {
-            let mut chars = Vec::<char>::new();
-            for char in line.chars() {
-                chars.push(char);
-            }
-
-            chars
-        })
-        .collect::<Vec<_>>();
-
+pub fn solve(input: &Vec<Vec<char>>) -> i64 {
     let mut indices: Vec<(usize, usize)> = Vec::new();
     let mut total_sum = 0;
 
@@ -84,15 +68,13 @@ pub fn run() {
 
     //println!("{:?}", indices);
     
-    let (expanded_rows_indices, expanded_cols_indices) = expand(&input);
+    let (expanded_rows_indices, expanded_cols_indices) = expand(input);
 
-    println!("Rows: {:?}", expanded_rows_indices);
-    println!("Cols: {:?}", expanded_cols_indices);
+    verbose!("Rows: {:?}", expanded_rows_indices);
+    verbose!("Cols: {:?}", expanded_cols_indices);
 
     for (i, start_indice) in indices.iter().enumerate() {
-        for j in i + 1..indices.len() {
-            let goal_indice = indices[j];
-
+        for goal_indice in indices.iter().skip(i + 1) {
             let manhattan = manhattan_distance(
                 start_indice.0 as i64,
                 start_indice.1 as i64,
@@ -112,8 +94,6 @@ pub fn run() {
         }
     }
 
-    assert_eq!(EXPECTED_ANSWER, total_sum);
-
-    println!("Result: {}", total_sum);
+    return total_sum;
 }
 
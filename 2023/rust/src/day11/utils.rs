@@ -0,0 +1,42 @@
+use crate::error::{Error, Result};
+use crate::io_utils::{check_grid, grid_errors, lint_lines};
+
+fn check_image_line(line_ix: usize, line: &str) -> Result<()> {
+    if let Some(unknown) = line.matches(|char| char != '.' && char != '#').next() {
+        return Err(Error::at(line_ix, line, unknown, "Expected only '.' and '#' in the image"));
+    }
+
+    return Ok(());
+}
+
+/// Reads the image into rows of `.` and `#`
+pub fn parse_image(input: &str) -> Result<Vec<Vec<char>>> {
+    check_grid(input)?;
+
+    for (line_ix, line) in input.lines().enumerate() {
+        check_image_line(line_ix, line)?;
+    }
+
+    return Ok(input.lines().map(|line| line.chars().collect()).collect());
+}
+
+pub fn lint(input: &str) -> Vec<Error> {
+    let mut errors = grid_errors(input);
+    errors.extend(lint_lines(input, check_image_line));
+
+    return errors;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::lint;
+
+    #[test]
+    fn lint_points_at_ragged_rows() {
+        let errors = lint("...#\n..\n#...\n.#...\n");
+
+        let positions = errors.iter().map(|error| (error.line, error.column)).collect::<Vec<_>>();
+
+        assert_eq!(positions, [(Some(2), Some(3)), (Some(4), Some(5))]);
+    }
+}
@@ -1,5 +1,49 @@
+use crate::error::{Error, Result};
+use crate::solution::{solve_input, Answer, Sample, Solution};
+
 pub mod part1;
 pub mod part2;
 
 pub mod defs;
-pub mod utils;
\ No newline at end of file
+pub mod utils;
+
+pub struct Day2;
+
+/// Parses the puzzle input and solves part 1 of it
+pub fn part1(input: &str) -> Result<Answer> {
+    return solve_input(&Day2, 1, input);
+}
+
+/// Parses the puzzle input and solves part 2 of it
+pub fn part2(input: &str) -> Result<Answer> {
+    return solve_input(&Day2, 2, input);
+}
+
+impl Solution for Day2 {
+    type Input = Vec<defs::Game>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    const DAY: u8 = 2;
+
+    const SAMPLES: &'static [Sample] = &[
+        Sample { part: 1, file: "input_sample.txt" },
+        Sample { part: 2, file: "input_sample.txt" },
+    ];
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        return utils::parse_games(input);
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Part1> {
+        return Ok(part1::solve(input));
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Part2> {
+        return Ok(part2::solve(input));
+    }
+
+    fn lint(&self, input: &str) -> Vec<Error> {
+        return utils::lint(input);
+    }
+}
@@ -30,8 +30,6 @@ Determine which games would have been possible if the bag had been loaded with o
 
 */
 
-use crate::day2::utils::parse_games_from_lines;
-
 use super::defs::Game;
 
 mod game_eval_module {
@@ -101,7 +99,7 @@ mod game_eval_module {
         }
 
         pub fn print_debug(&self) {
-            println!("{} -> is_possible: {}", self.game.ref_line, self.is_possible);
+            trace!("{} -> is_possible: {}", self.game.ref_line, self.is_possible);
         }
     }
 
@@ -113,7 +111,7 @@ mod game_eval_module {
     }
 }
 
-fn eval_games(games: &Vec<Game>) -> game_eval_module::GameEvalResult {
+fn eval_games(games: &Vec<Game>) -> game_eval_module::GameEvalResult<'_> {
     let mut game_evals = Vec::<game_eval_module::GameEval>::with_capacity(games.len());
     let mut total_possible = 0;
     let mut sum_of_game_ids = 0;
@@ -138,13 +136,8 @@ fn eval_games(games: &Vec<Game>) -> game_eval_module::GameEvalResult {
     };
 }
 
-const EXPECTED_ANSWER: usize = 2563;
-
-pub fn run() {
-    let games = parse_games_from_lines();
-    let eval_result = eval_games(&games);
-
-    assert_eq!(EXPECTED_ANSWER, eval_result.sum_of_game_ids, "Expected {}, got {}", EXPECTED_ANSWER, eval_result.sum_of_game_ids);
+pub fn solve(games: &Vec<Game>) -> usize {
+    let eval_result = eval_games(games);
 
-    println!("Sum of game ids: {:?}", eval_result.sum_of_game_ids);
+    return eval_result.sum_of_game_ids;
 }
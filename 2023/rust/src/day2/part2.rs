@@ -26,9 +26,7 @@ For each game, find the minimum set of cubes that must have been present. What i
 
 */
 
-use crate::day2::utils::parse_games_from_lines;
-
-use super::defs::GameSet;
+use super::defs::{Game, GameSet};
 
 #[derive(Debug)]
 struct PowerMinSetOfCubes {
@@ -38,8 +36,6 @@ struct PowerMinSetOfCubes {
     power: usize
 }
 
-const EXPECTED_ANSWER: usize = 70768;
-
 fn find_game_minimum_set_power(sets: &Vec::<GameSet>) -> PowerMinSetOfCubes {
     let mut highest_red = 0;
     let mut highest_green = 0;
@@ -66,18 +62,15 @@ fn find_game_minimum_set_power(sets: &Vec::<GameSet>) -> PowerMinSetOfCubes {
     return PowerMinSetOfCubes { highest_red, highest_green, highest_blue, power: highest_red * highest_green * highest_blue } ;
 }
 
-pub fn run() {
-    let games = parse_games_from_lines();
+pub fn solve(games: &Vec<Game>) -> usize {
     let mut total_power_sum = 0;
 
     for game in games {
         let power_result = find_game_minimum_set_power(&game.sets);
-        println!("{:?}", power_result);
+        trace!("{:?}", power_result);
 
         total_power_sum += power_result.power;
     }
 
-    assert_eq!(EXPECTED_ANSWER, total_power_sum);
-
-    println!("Total sum is: {}", total_power_sum);
+    return total_power_sum;
 }
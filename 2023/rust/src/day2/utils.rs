@@ -1,16 +1,42 @@
-use crate::io_utils::read_lines;
+use crate::error::{Error, Result};
 
 use super::defs::{Game, GameSet};
 
-pub fn parse_game_line(line: &str) -> Game {
-    let initial_split: Vec<&str> = line.split(":").collect();
+/// Splits a `<amount> <colour>` cube count, checking the colour is one of the known ones
+fn parse_cube<'a>(line_ix: usize, line: &str, collected_cube: &'a str) -> Result<(usize, &'a str)> {
+    let (amount_text, cube_color) = collected_cube
+        .split_once(' ')
+        .ok_or_else(|| Error::at(line_ix, line, collected_cube, "Expected '<amount> <colour>'"))?;
 
-    assert_eq!(initial_split.len(), 2);
+    let amount: usize = amount_text
+        .parse()
+        .map_err(|_| Error::at(line_ix, line, amount_text, "Cube amount is not a number"))?;
 
-    let game_meta_split: Vec<&str> = initial_split.get(0).unwrap().split(" ").collect();
-    let game_id: usize = game_meta_split.last().unwrap().parse().unwrap();
+    if !matches!(cube_color, "red" | "green" | "blue") {
+        return Err(Error::at(line_ix, line, cube_color, "Unknown cube colour"));
+    }
+
+    return Ok((amount, cube_color));
+}
+
+/// Splits a game line into its id and the text of its sets
+fn split_game_line(line_ix: usize, line: &str) -> Result<(usize, &str)> {
+    let (game_meta, sets) = line
+        .split_once(':')
+        .ok_or_else(|| Error::on_line(line_ix, line, "Expected 'Game <id>:' before the sets"))?;
+
+    let game_id_text = game_meta.strip_prefix("Game ").unwrap_or(game_meta);
+    let game_id: usize = game_id_text
+        .parse()
+        .map_err(|_| Error::at(line_ix, line, game_id_text, "Game id is not a number"))?;
+
+    return Ok((game_id, sets));
+}
+
+pub fn parse_game_line(line_ix: usize, line: &str) -> Result<Game> {
+    let (game_id, sets) = split_game_line(line_ix, line)?;
     
-    let set_splits: Vec<&str> = initial_split.get(1).unwrap().split(";").collect();
+    let set_splits: Vec<&str> = sets.split(';').collect();
     let mut game_sets: Vec<GameSet> = Vec::with_capacity(set_splits.len());
 
     for set in set_splits {
@@ -21,40 +47,68 @@ pub fn parse_game_line(line: &str) -> Game {
         let mut blue = Option::<usize>::None;
         
         for collected_cube in collected_cubes {
-            let cube_meta: Vec<&str> = collected_cube.split(" ").collect();
-
-            let amount: usize = cube_meta.get(0).unwrap().parse().unwrap();
-            let cube_color = cube_meta.get(1).unwrap().to_owned();
+            let (amount, cube_color) = parse_cube(line_ix, line, collected_cube)?;
 
             match cube_color {
                 "red" => red = Option::Some(amount),
                 "green" => green = Option::Some(amount),
-                "blue" => blue = Option::Some(amount),
-                _other => {}
+                _blue => blue = Option::Some(amount),
             }
         }
 
         game_sets.push(GameSet { red, green, blue })
     }
     
-    return Game {
+    return Ok(Game {
         id: game_id,
         sets: game_sets,
         ref_line: line.to_owned(),
-    }
+    });
 }
 
-pub fn parse_games_from_lines() -> Vec<Game> {
-    let lines_result = read_lines("src/day2/input.txt");
+pub fn parse_games(input: &str) -> Result<Vec<Game>> {
     let mut parsed_games = Vec::<Game>::new();
 
-    if let Ok(lines) = lines_result {
-        for line in lines {
-            if let Ok(the_line) = line {
-                parsed_games.push(parse_game_line(&the_line))
+    for (line_ix, the_line) in input.lines().enumerate() {
+        parsed_games.push(parse_game_line(line_ix, the_line)?)
+    }
+
+    return Ok(parsed_games);
+}
+
+/// Checks every cube of every game, rather than stopping at the first bad one like parsing
+pub fn lint(input: &str) -> Vec<Error> {
+    let mut errors = Vec::<Error>::new();
+
+    for (line_ix, line) in input.lines().enumerate() {
+        let sets = match split_game_line(line_ix, line) {
+            Ok((_, sets)) => sets,
+            Err(error) => {
+                errors.push(error);
+                continue;
+            }
+        };
+
+        for collected_cube in sets.split(';').flat_map(|set| set.trim().split(", ")) {
+            if let Err(error) = parse_cube(line_ix, line, collected_cube) {
+                errors.push(error);
             }
         }
     }
 
-    return parsed_games;
+    return errors;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::lint;
+
+    #[test]
+    fn lint_points_at_an_unknown_colour() {
+        let errors = lint("Game 1: 3 blue, 4 red\nGame 2: 1 red, 2 purple; 5 green\n");
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!((errors[0].line, errors[0].column), (Some(2), Some(18)));
+        assert_eq!(errors[0].text.as_deref(), Some("purple"));
+    }
 }
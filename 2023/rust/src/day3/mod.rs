@@ -1 +1,40 @@
-pub mod part1;
\ No newline at end of file
+use crate::error::{Error, Result};
+use crate::solution::{solve_input, Answer, Sample, Solution};
+
+pub mod part1;
+
+pub struct Day3;
+
+/// Parses the puzzle input and solves part 1 of it
+pub fn part1(input: &str) -> Result<Answer> {
+    return solve_input(&Day3, 1, input);
+}
+
+impl Solution for Day3 {
+    type Input = Vec<String>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    const DAY: u8 = 3;
+    const PARTS: &'static [u8] = &[1];
+
+    const SAMPLES: &'static [Sample] = &[
+        Sample { part: 1, file: "input_sample.txt" },
+    ];
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        return part1::read_schematic(input);
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Part1> {
+        return Ok(part1::solve(input));
+    }
+
+    fn part2(&self, _input: &Self::Input) -> Result<Self::Part2> {
+        unreachable!("Day 3 part 2 has not been solved yet");
+    }
+
+    fn lint(&self, input: &str) -> Vec<Error> {
+        return part1::lint(input);
+    }
+}
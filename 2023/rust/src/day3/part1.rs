@@ -32,7 +32,8 @@ Of course, the actual engine schematic is much larger. What is the sum of all of
 
 */
 
-use crate::io_utils::read_lines;
+use crate::error::{Error, Result};
+use crate::io_utils::{check_grid, grid_errors, lint_lines};
 
 #[derive(Debug, Clone)]
 struct NumericChar {
@@ -49,20 +50,39 @@ struct LineMeta {
 
 const BLANK_SYMBOL: char = '.';
 
-fn read_schematic() -> Vec<String> {
-    let mut schematic = Vec::<String>::new();
+pub fn read_schematic(input: &str) -> Result<Vec<String>> {
+    check_grid(input)?;
 
-    let lines_result = read_lines("src/day3/input_sample.txt");
+    if input.lines().count() < 2 {
+        return Err(Error::new("Schematic needs at least 2 lines"));
+    }
 
-    if let Ok(lines) = lines_result {
-        for line in lines {
-            if let Ok(the_line) = line {
-                schematic.push(the_line);
-            }
-        }
+    for (line_ix, line) in input.lines().enumerate() {
+        check_ascii(line_ix, line)?;
+    }
+
+    return Ok(input.lines().map(|line| line.to_owned()).collect());
+}
+
+/// The schematic is looked at byte by byte, so it can only hold ASCII
+fn check_ascii(line_ix: usize, line: &str) -> Result<()> {
+    if let Some(non_ascii) = line.matches(|char: char| !char.is_ascii()).next() {
+        return Err(Error::at(line_ix, line, non_ascii, "Schematic can only hold ASCII characters"));
     }
 
-    return schematic;
+    return Ok(());
+}
+
+pub fn lint(input: &str) -> Vec<Error> {
+    let mut errors = grid_errors(input);
+
+    if input.lines().count() == 1 {
+        errors.push(Error::new("Schematic needs at least 2 lines"));
+    }
+
+    errors.extend(lint_lines(input, check_ascii));
+
+    return errors;
 }
 
 fn num_buffer_to_number(buffer: &Vec<NumericChar>) -> usize {
@@ -79,7 +99,7 @@ fn extract_line_meta(schematic: &Vec<String>) -> Vec<LineMeta> {
     let mut all_numbers = Vec::<LineMeta>::new();
 
     for (line_ix, line) in schematic.iter().enumerate() {
-        println!("{}", line);
+        trace!("{}", line);
 
         let mut num_buffer = Vec::<NumericChar>::new();
 
@@ -99,7 +119,7 @@ fn extract_line_meta(schematic: &Vec<String>) -> Vec<LineMeta> {
                     num_buffer.clear();
                 }
             } else {
-                if num_buffer.len() > 0 {
+                if !num_buffer.is_empty() {
                     all_numbers.push(LineMeta {
                         ix: line_ix,
                         num_chars: num_buffer.clone(),
@@ -191,7 +211,7 @@ fn calc_valid_parts_sum(valid_parts: Vec<Vec<NumericChar>>) -> usize {
         
         let part_num = num_buffer_to_number(&valid_part);
 
-        println!("{}", part_num);
+        trace!("{}", part_num);
 
         total_sum += part_num;
     }
@@ -199,8 +219,8 @@ fn calc_valid_parts_sum(valid_parts: Vec<Vec<NumericChar>>) -> usize {
     return total_sum;
 }
 
-fn analyse_schematic(schematic: Vec<String>) {
-    let line_metas = extract_line_meta(&schematic);
+fn analyse_schematic(schematic: &Vec<String>) -> usize {
+    let line_metas = extract_line_meta(schematic);
 
     let mut valid_parts = Vec::<(usize, Vec<NumericChar>)>::new();
 
@@ -214,7 +234,7 @@ fn analyse_schematic(schematic: Vec<String>) {
             if line_meta.ix == 0 {
                 let line_below_chars = schematic.get(line_meta.ix + 1).unwrap().as_bytes();
 
-                if is_symbol_around(ix, number, &line_meta, &this_line, Some(&line_below_chars), None) {
+                if is_symbol_around(ix, number, &line_meta, this_line, Some(line_below_chars), None) {
                     is_valid = true;
                     break;
                 }
@@ -227,7 +247,7 @@ fn analyse_schematic(schematic: Vec<String>) {
             } else if line_meta.ix == schematic.len() - 1 {
                 let line_above_chars = schematic.get(line_meta.ix - 1).unwrap().as_bytes();
 
-                if is_symbol_around(ix, number, &line_meta, &this_line, None, Some(&line_above_chars)) {
+                if is_symbol_around(ix, number, &line_meta, this_line, None, Some(line_above_chars)) {
                     is_valid = true;
                     break;
                 }
@@ -245,9 +265,9 @@ fn analyse_schematic(schematic: Vec<String>) {
                     ix,
                     number,
                     &line_meta,
-                    &this_line,
-                    Some(&line_below_chars),
-                    Some(&line_above_chars),
+                    this_line,
+                    Some(line_below_chars),
+                    Some(line_above_chars),
                 ) {
                     is_valid = true;
                     break;
@@ -277,9 +297,9 @@ fn analyse_schematic(schematic: Vec<String>) {
 
     let total_sum = calc_valid_parts_sum(valid_parts.iter().map(|part| part.1.clone()).collect());
 
-    println!("Total sum of all valid parts: {}", total_sum);
+    return total_sum;
 }
 
-pub fn run() {
-    analyse_schematic(read_schematic());
+pub fn solve(schematic: &Vec<String>) -> usize {
+    return analyse_schematic(schematic);
 }
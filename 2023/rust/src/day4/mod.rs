@@ -1,4 +1,50 @@
+use std::collections::HashMap;
+
+use crate::error::{Error, Result};
+use crate::solution::{solve_input, Answer, Sample, Solution};
+
 pub mod part1;
 pub mod part2;
 pub mod defs;
-pub mod utils;
\ No newline at end of file
+pub mod utils;
+
+pub struct Day4;
+
+/// Parses the puzzle input and solves part 1 of it
+pub fn part1(input: &str) -> Result<Answer> {
+    return solve_input(&Day4, 1, input);
+}
+
+/// Parses the puzzle input and solves part 2 of it
+pub fn part2(input: &str) -> Result<Answer> {
+    return solve_input(&Day4, 2, input);
+}
+
+impl Solution for Day4 {
+    type Input = HashMap<usize, defs::CardState>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    const DAY: u8 = 4;
+
+    const SAMPLES: &'static [Sample] = &[
+        Sample { part: 1, file: "input_sample.txt" },
+        Sample { part: 2, file: "input_sample.txt" },
+    ];
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        return utils::parse_card_lines(utils::read_card_lines(input));
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Part1> {
+        return Ok(part1::solve(input));
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Part2> {
+        return Ok(part2::solve(input));
+    }
+
+    fn lint(&self, input: &str) -> Vec<Error> {
+        return utils::lint(input);
+    }
+}
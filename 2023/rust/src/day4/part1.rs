@@ -38,20 +38,17 @@ Take a seat in the large pile of colorful cards. How many points are they worth
 
 */
 
-use crate::day4::utils::{read_card_lines, parse_card_lines};
+use std::collections::HashMap;
 
-const EXPECTED_ANSWER: usize = 25231;
-
-pub fn run() {
-    let card_lines = read_card_lines();
-    let cards = parse_card_lines(card_lines);
+use super::defs::CardState;
 
+pub fn solve(cards: &HashMap<usize, CardState>) -> usize {
     let mut total_card_points = 0;
 
-    for (ix, card) in cards.into_iter().enumerate() {
+    for (ix, card) in cards.iter().enumerate() {
         let mut card_score = 0;
-        for win_number in card.1.winning_numbers {
-            if card.1.my_numbers.contains(&win_number) {
+        for win_number in card.1.winning_numbers.iter() {
+            if card.1.my_numbers.contains(win_number) {
                 if card_score == 0 {
                     card_score = 1;
                 } else {
@@ -60,12 +57,10 @@ pub fn run() {
             }
         }
 
-        println!("Card {} score -> {}", ix, card_score);
+        trace!("Card {} score -> {}", ix, card_score);
 
         total_card_points += card_score;        
     }
 
-    assert_eq!(EXPECTED_ANSWER, total_card_points);
-
-    println!("Total card score -> {}", total_card_points);
+    return total_card_points;
 }
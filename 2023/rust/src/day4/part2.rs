@@ -35,16 +35,11 @@ Process all of the original and copied scratchcards until no more scratchcards a
 
 use std::collections::HashMap;
 
-use crate::day4::utils::{parse_card_lines, read_card_lines};
+use super::defs::CardState;
 
 type TimesToProcessCard = usize;
 
-const EXPECTED_ANSWER: usize = 9721255;
-
-pub fn run() {
-    let card_lines = read_card_lines();
-    let original_cards = parse_card_lines(card_lines);
-
+pub fn solve(original_cards: &HashMap<usize, CardState>) -> usize {
     let mut card_processing_map = HashMap::<usize, TimesToProcessCard>::new();
 
     original_cards
@@ -59,7 +54,7 @@ pub fn run() {
         let mut total_matching_numbers_for_current_card = 0;
     
         for win_number in the_card.winning_numbers.iter() {
-            if the_card.my_numbers.contains(&win_number) {
+            if the_card.my_numbers.contains(win_number) {
                 total_matching_numbers_for_current_card += 1;
             }
         }
@@ -86,7 +81,5 @@ pub fn run() {
         total_scratch_cards_won += *entry.1;
     });
 
-    assert_eq!(EXPECTED_ANSWER, total_scratch_cards_won);
-
-    println!("{}", total_scratch_cards_won);
+    return total_scratch_cards_won;
 }
@@ -1,53 +1,39 @@
 use std::collections::{HashSet, HashMap};
 
-use crate::io_utils::read_lines;
+use crate::error::{Error, Result};
+use crate::io_utils::{lint_lines, parse_numbers};
 
 use super::defs::CardState;
 
-pub fn read_card_lines() -> Vec<String> {
-    let mut card_lines = Vec::<String>::new();
+pub fn read_card_lines(input: &str) -> Vec<String> {
+    return input.lines().map(|line| line.to_owned()).collect();
+}
 
-    let line_read_result = read_lines("src/day4/input.txt");
+fn parse_card_line(ix: usize, card_line: &str) -> Result<CardState> {
+    let (_card_meta, numbers) = card_line
+        .split_once(':')
+        .ok_or_else(|| Error::on_line(ix, card_line, "Expected 'Card <id>:' before the numbers"))?;
 
-    if let Ok(lines) = line_read_result {
-        for line in lines {
-            if let Ok(the_line) = line {
-                card_lines.push(the_line);
-            }
-        }
-    }
+    let (winning_numbers, my_numbers) = numbers
+        .split_once(" | ")
+        .ok_or_else(|| Error::at(ix, card_line, numbers, "Expected winning numbers and my numbers split by ' | '"))?;
+
+    let winning_numbers: HashSet<usize> = parse_numbers::<usize>(ix, card_line, winning_numbers)?.into_iter().collect();
+    let my_numbers: HashSet<usize> = parse_numbers::<usize>(ix, card_line, my_numbers)?.into_iter().collect();
 
-    return card_lines;
+    return Ok(CardState { winning_numbers, my_numbers });
 }
 
-pub fn parse_card_lines(card_lines: Vec<String>) -> HashMap<usize, CardState> {
+pub fn parse_card_lines(card_lines: Vec<String>) -> Result<HashMap<usize, CardState>> {
     let mut card_maps = HashMap::<usize, CardState>::new();
 
     for (ix, card_line) in card_lines.iter().enumerate() {
-        let first_split: Vec<&str> = card_line.split(":").collect();
-
-        let numbers_split: Vec<&str> = first_split.get(1).unwrap().split(" | ").collect();
-
-        let winning_numbers: Result<HashSet<usize>, _> = numbers_split
-            .get(0)
-            .unwrap()
-            .split(" ")
-            .filter(|item| !item.is_empty())
-            .map(|item| item.parse())
-            .collect();
-
-        let my_numbers: Result<HashSet<usize>, _> = numbers_split
-            .get(1)
-            .unwrap()
-            .split(" ")
-            .filter(|item| !item.is_empty())
-            .map(|item| item.parse())
-            .collect();
-
-        if let (Ok(winning_numbers), Ok(my_numbers)) = (winning_numbers, my_numbers) {
-            card_maps.insert(ix, CardState { winning_numbers, my_numbers });
-        }
+        card_maps.insert(ix, parse_card_line(ix, card_line)?);
     }
 
-    return card_maps;
+    return Ok(card_maps);
+}
+
+pub fn lint(input: &str) -> Vec<Error> {
+    return lint_lines(input, |ix, card_line| parse_card_line(ix, card_line).map(|_| ()));
 }
@@ -1,19 +1,41 @@
 use std::collections::HashMap;
 
-use super::defs::{Almanac, MapEntryDescription, MapType, SourceToDestinationMap};
+use crate::error::{Error, Result};
+use crate::io_utils::{parse_labelled_numbers, parse_numbers};
 
-pub fn parse_almanac(lines: Vec<String>) -> Option<Almanac> {
-    let mut lines_iter = lines.iter();
+use super::defs::{Almanac, MapEntryDescription, MapType, SourceToDestinationMap};
 
-    let seeds_line_split: Vec<&str> = lines_iter.next().unwrap().split(": ").collect();
-    let seeds_line: &str = seeds_line_split.get(1).unwrap();
+const MAP_HEADINGS: [(&str, MapType); 7] = [
+    ("seed-to-soil map", MapType::SeedToSoil),
+    ("soil-to-fertilizer", MapType::SoilToFertilizer),
+    ("fertilizer-to-water", MapType::FertizilerToWater),
+    ("water-to-light", MapType::WaterToLight),
+    ("light-to-temperature", MapType::LightToTemperate),
+    ("temperature-to-humidity", MapType::TemperatureToHumidity),
+    ("humidity-to-location", MapType::HumidityToLocation),
+];
 
-    let seeds_str: Vec<&str> = seeds_line.split(" ").collect();
-    let seeds: Vec<i64> = seeds_str
+fn heading_map_type(line: &str) -> Option<MapType> {
+    return MAP_HEADINGS
         .iter()
-        .filter(|str| !str.is_empty())
-        .map(|num_str| num_str.parse().unwrap())
-        .collect();
+        .find(|(heading, _)| line.starts_with(heading))
+        .map(|(_, map_type)| *map_type);
+}
+
+/// Parses a `<destination start> <source start> <length>` map entry
+fn parse_map_entry(line_ix: usize, line: &str) -> Result<[i64; 3]> {
+    let map_entry: Vec<i64> = parse_numbers(line_ix, line, line)?;
+
+    return map_entry.try_into().map_err(|map_entry: Vec<i64>| {
+        Error::on_line(line_ix, line, format!("Expected 3 numbers in a map entry, found {}", map_entry.len()))
+    });
+}
+
+pub fn parse_almanac(lines: Vec<String>) -> Result<Almanac> {
+    let mut lines_iter = lines.iter();
+
+    let seeds_line = lines_iter.next().ok_or_else(|| Error::new("Almanac is empty"))?;
+    let seeds = parse_labelled_numbers::<i64>(0, seeds_line, "seeds")?;
 
     let mut maps = HashMap::<MapType, Vec<MapEntryDescription>>::new();
 
@@ -21,33 +43,17 @@ pub fn parse_almanac(lines: Vec<String>) -> Option<Almanac> {
     let mut map_read = Vec::<MapEntryDescription>::new();
 
     for (ix, line) in lines_iter.enumerate() {
+        // the seeds line has already been taken off the iterator
+        let line_ix = ix + 1;
+
         if map_read_type.is_none() {
-            if line.starts_with("seed-to-soil map") {
-                map_read_type = Some(MapType::SeedToSoil);
-                continue;
-            } else if line.starts_with("soil-to-fertilizer") {
-                map_read_type = Some(MapType::SoilToFertilizer);
-                continue;
-            } else if line.starts_with("fertilizer-to-water") {
-                map_read_type = Some(MapType::FertizilerToWater);
-                continue;
-            } else if line.starts_with("water-to-light") {
-                map_read_type = Some(MapType::WaterToLight);
-                continue;
-            } else if line.starts_with("light-to-temperature") {
-                map_read_type = Some(MapType::LightToTemperate);
-                continue;
-            } else if line.starts_with("temperature-to-humidity") {
-                map_read_type = Some(MapType::TemperatureToHumidity);
-                continue;
-            } else if line.starts_with("humidity-to-location") {
-                map_read_type = Some(MapType::HumidityToLocation);
+            if let Some(heading_type) = heading_map_type(line) {
+                map_read_type = Some(heading_type);
                 continue;
             }
         }
 
-        // -2 since we .next() at the start to get the seeds
-        if line.is_empty() || ix == lines.len() - 2 {
+        if line.is_empty() {
             if let Some(map_read_type) = map_read_type {
                 maps.insert(map_read_type, map_read.clone());
             }
@@ -58,13 +64,11 @@ pub fn parse_almanac(lines: Vec<String>) -> Option<Almanac> {
             continue;
         }
 
-        let map_entry_split: Vec<&str> = line.split(" ").collect();
-
-        assert_eq!(map_entry_split.len(), 3);
+        if map_read_type.is_none() {
+            return Err(Error::on_line(line_ix, line, "Map entry is not under a known map heading"));
+        }
 
-        let destination_range_start = map_entry_split.get(0).unwrap().parse().unwrap();
-        let source_range_start = map_entry_split.get(1).unwrap().parse().unwrap();
-        let range_length = map_entry_split.get(2).unwrap().parse().unwrap();
+        let [destination_range_start, source_range_start, range_length] = parse_map_entry(line_ix, line)?;
 
         map_read.push(MapEntryDescription {
             source_range_start,
@@ -75,30 +79,70 @@ pub fn parse_almanac(lines: Vec<String>) -> Option<Almanac> {
         });
     }
 
-    return Some(Almanac {
+    // the last map ends with the input rather than with a blank line
+    if let Some(map_read_type) = map_read_type {
+        maps.insert(map_read_type, map_read);
+    }
+
+    let mut take_map = |map_type: MapType| {
+        return maps
+            .remove(&map_type)
+            .map(|mappings| SourceToDestinationMap::new(map_type, mappings))
+            .ok_or_else(|| Error::new(format!("Almanac has no {:?} map", map_type)));
+    };
+
+    return Ok(Almanac {
         seeds,
-        seed_to_soil_map: SourceToDestinationMap::new(
-            MapType::SeedToSoil, maps.get(&MapType::SeedToSoil).unwrap().to_owned(),
-        ),
-        soil_to_fertilizier_map: SourceToDestinationMap::new(
-            MapType::SoilToFertilizer, maps.get(&MapType::SoilToFertilizer).unwrap().to_owned(),
-        ),
-        fertizilier_to_water_map: SourceToDestinationMap::new(
-            MapType::FertizilerToWater, maps.get(&MapType::FertizilerToWater).unwrap().to_owned(),
-        ),
-        water_to_light_map: SourceToDestinationMap::new(
-            MapType::WaterToLight, maps.get(&MapType::WaterToLight).unwrap().to_owned(),
-        ),
-        light_to_temperature: SourceToDestinationMap::new(
-            MapType::LightToTemperate, maps.get(&MapType::LightToTemperate).unwrap().to_owned(),
-        ),
-        temperature_to_humidity: SourceToDestinationMap::new(
-            MapType::TemperatureToHumidity, maps.get(&MapType::TemperatureToHumidity)
-                .unwrap()
-                .to_owned(),
-        ),
-        humidity_to_location: SourceToDestinationMap::new(
-            MapType::HumidityToLocation, maps.get(&MapType::HumidityToLocation).unwrap().to_owned(),
-        ),
+        seed_to_soil_map: take_map(MapType::SeedToSoil)?,
+        soil_to_fertilizier_map: take_map(MapType::SoilToFertilizer)?,
+        fertizilier_to_water_map: take_map(MapType::FertizilerToWater)?,
+        water_to_light_map: take_map(MapType::WaterToLight)?,
+        light_to_temperature: take_map(MapType::LightToTemperate)?,
+        temperature_to_humidity: take_map(MapType::TemperatureToHumidity)?,
+        humidity_to_location: take_map(MapType::HumidityToLocation)?,
     });
-}
\ No newline at end of file
+}
+
+/// Checks every line of the almanac, rather than stopping at the first bad one like parsing
+pub fn lint(input: &str) -> Vec<Error> {
+    let mut errors = Vec::<Error>::new();
+    let mut lines = input.lines().enumerate();
+
+    match lines.next() {
+        Some((_, seeds_line)) => errors.extend(parse_labelled_numbers::<i64>(0, seeds_line, "seeds").err()),
+        None => errors.push(Error::new("Almanac is empty")),
+    }
+
+    let mut in_map = false;
+
+    for (line_ix, line) in lines {
+        if line.is_empty() {
+            in_map = false;
+        } else if heading_map_type(line).is_some() {
+            in_map = true;
+        } else if line.ends_with("map:") {
+            errors.push(Error::on_line(line_ix, line, "Unknown map heading"));
+            in_map = false;
+        } else if !in_map {
+            errors.push(Error::on_line(line_ix, line, "Map entry is not under a known map heading"));
+        } else {
+            errors.extend(parse_map_entry(line_ix, line).err());
+        }
+    }
+
+    return errors;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::lint;
+
+    #[test]
+    fn lint_points_at_a_map_entry_without_three_numbers() {
+        let errors = lint("seeds: 79 14\n\nseed-to-soil map:\n50 98 2\n52 50\n");
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!((errors[0].line, errors[0].column), (Some(5), Some(1)));
+        assert_eq!(errors[0].message, "Expected 3 numbers in a map entry, found 2");
+    }
+}
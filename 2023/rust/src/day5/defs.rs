@@ -27,9 +27,9 @@ impl SourceToDestinationMap {
         for item in &self.mappings {
             if source >= item.source_range_start && source <= item.source_range_end {
 
-                let offset_for_destination = ((item.source_range_start as i64) - (source as i64)).abs();
+                let offset_for_destination = (item.source_range_start - source).abs();
                 destination = 
-                    Some((item.destination_range_start) + offset_for_destination as i64);
+                    Some((item.destination_range_start) + offset_for_destination);
                 break;
             }
         }
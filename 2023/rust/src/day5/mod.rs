@@ -1,4 +1,50 @@
+use crate::error::{Error, Result};
+use crate::solution::{solve_input, Answer, Sample, Solution};
+
 pub mod part1;
 pub mod part2;
 pub mod defs;
-pub mod almanac;
\ No newline at end of file
+pub mod almanac;
+
+pub struct Day5;
+
+/// Parses the puzzle input and solves part 1 of it
+pub fn part1(input: &str) -> Result<Answer> {
+    return solve_input(&Day5, 1, input);
+}
+
+/// Parses the puzzle input and solves part 2 of it
+pub fn part2(input: &str) -> Result<Answer> {
+    return solve_input(&Day5, 2, input);
+}
+
+impl Solution for Day5 {
+    type Input = defs::Almanac;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    const DAY: u8 = 5;
+
+    const SAMPLES: &'static [Sample] = &[
+        Sample { part: 1, file: "input_sample.txt" },
+        Sample { part: 2, file: "input_sample.txt" },
+    ];
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        let lines = input.lines().map(|line| line.to_owned()).collect();
+
+        return almanac::parse_almanac(lines);
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Part1> {
+        return Ok(part1::solve(input));
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Part2> {
+        return part2::solve(input);
+    }
+
+    fn lint(&self, input: &str) -> Vec<Error> {
+        return almanac::lint(input);
+    }
+}
@@ -103,40 +103,30 @@ What is the lowest location number that corresponds to any of the initial seed n
 
 */
 
-use crate::io_utils::read_lines_fully;
+use super::defs::Almanac;
 
-use super::almanac::parse_almanac;
+pub fn solve(almanac: &Almanac) -> i64 {
+    let mut lowest_location: i64 = i64::MAX;
 
-const EXPECTED_ANSWER: i64 = 31599214;
+    almanac.seeds.iter().for_each(|seed| {
 
-pub fn run() {
-    let lines = read_lines_fully("src/day5/input.txt");
+        trace!("Processing seed: {}", *seed);
 
-    if let Some(almanac) = parse_almanac(lines) {
-        let mut lowest_location: i64 = i64::MAX;
+        let mut location = *seed;
+        location = almanac.seed_to_soil_map.destination(location);
+        location = almanac.soil_to_fertilizier_map.destination(location);
+        location = almanac.fertizilier_to_water_map.destination(location);
+        location = almanac.water_to_light_map.destination(location);
+        location = almanac.light_to_temperature.destination(location);
+        location = almanac.temperature_to_humidity.destination(location);
+        location = almanac.humidity_to_location.destination(location);
 
-        almanac.seeds.iter().for_each(|seed| {
+        if location < lowest_location {
+            lowest_location = location;
+        }
 
-            println!("Processing seed: {}", *seed);
+        trace!("{}", location);    
+    });
 
-            let mut location = *seed;
-            location = almanac.seed_to_soil_map.destination(location);
-            location = almanac.soil_to_fertilizier_map.destination(location);
-            location = almanac.fertizilier_to_water_map.destination(location);
-            location = almanac.water_to_light_map.destination(location);
-            location = almanac.light_to_temperature.destination(location);
-            location = almanac.temperature_to_humidity.destination(location);
-            location = almanac.humidity_to_location.destination(location);
-
-            if location < lowest_location {
-                lowest_location = location;
-            }
-
-            println!("{}", location);    
-        });
-
-        assert_eq!(EXPECTED_ANSWER, lowest_location);
-
-        println!("{}", lowest_location);
-    }
+    return lowest_location;
 }
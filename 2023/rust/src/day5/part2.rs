@@ -19,11 +19,9 @@ Consider all of the initial seed numbers listed in the ranges on the first line
 
 */
 
-use crate::{io_utils::read_lines_fully, day5::defs::SeedRangePair};
-
-use super::{almanac::parse_almanac, defs::Almanac};
-
-const EXPECTED_ANSWER: i64 = 31599214;
+use super::defs::{Almanac, SeedRangePair};
+use crate::cancel;
+use crate::error::{Error, Result};
 
 fn binary_search(almanac: &Almanac, seed_range_pair: &SeedRangePair, lowest_location: &mut i64) {
     let mut low = seed_range_pair.start;
@@ -49,60 +47,63 @@ fn binary_search(almanac: &Almanac, seed_range_pair: &SeedRangePair, lowest_loca
     }
 }
 
-pub fn run() {
-    let lines = read_lines_fully("src/day5/input.txt");
+pub fn solve(almanac: &Almanac) -> Result<i64> {
+    let mut lowest_location: i64 = i64::MAX;
 
-    if let Some(almanac) = parse_almanac(lines) {
-        let mut lowest_location: i64 = i64::MAX;
+    if !almanac.seeds.len().is_multiple_of(2) {
+        return Err(Error {
+            line: Some(1),
+            ..Error::new(format!("Expected seeds as start and length pairs, found {} numbers", almanac.seeds.len()))
+        });
+    }
 
-        assert_eq!(almanac.seeds.len() % 2, 0);
+    let mut seed_range_pairs = Vec::<SeedRangePair>::new();
 
-        let mut seed_range_pairs = Vec::<SeedRangePair>::new();
+    let iterations = almanac.seeds.len() / 2;
 
-        let iterations = almanac.seeds.len() / 2;
+    let mut first_ix = 0;
+    let mut second_ix = 1;
 
-        let mut first_ix = 0;
-        let mut second_ix = 1;
+    for _ in 0..iterations {
+        let seed_range_start = *almanac.seeds.get(first_ix).unwrap();
+        let seed_range_length = *almanac.seeds.get(second_ix).unwrap();
 
-        for _ in 0..iterations {
-            let seed_range_start = *almanac.seeds.get(first_ix).unwrap();
-            let seed_range_length = *almanac.seeds.get(second_ix).unwrap();
+        seed_range_pairs.push(SeedRangePair {
+            start: seed_range_start,
+            end: seed_range_start + seed_range_length - 1,
+            length: seed_range_length,
+        });
 
-            seed_range_pairs.push(SeedRangePair {
-                start: seed_range_start,
-                end: seed_range_start + seed_range_length - 1,
-                length: seed_range_length,
-            });
+        first_ix += 2;
+        second_ix += 2;
+    }
 
-            first_ix += 2;
-            second_ix += 2;
-        }
+    let cancel = cancel::current();
 
-        for seed_range in &seed_range_pairs {
-            for seed in seed_range.start..=seed_range.end {
-                let mut location = seed;
-                location = almanac.seed_to_soil_map.destination(location);
-                location = almanac.soil_to_fertilizier_map.destination(location);
-                location = almanac.fertizilier_to_water_map.destination(location);
-                location = almanac.water_to_light_map.destination(location);
-                location = almanac.light_to_temperature.destination(location);
-                location = almanac.temperature_to_humidity.destination(location);
-                location = almanac.humidity_to_location.destination(location);
-    
-                if location < lowest_location {
-                    lowest_location = location;
-                }
-            }
-        }
+    for seed_range in &seed_range_pairs {
+        for seed in seed_range.start..=seed_range.end {
+            cancel.checkpoint();
 
-        // TODO: the trick seems to be to use reverse lookups since location is the important value, not the seed.
+            let mut location = seed;
+            location = almanac.seed_to_soil_map.destination(location);
+            location = almanac.soil_to_fertilizier_map.destination(location);
+            location = almanac.fertizilier_to_water_map.destination(location);
+            location = almanac.water_to_light_map.destination(location);
+            location = almanac.light_to_temperature.destination(location);
+            location = almanac.temperature_to_humidity.destination(location);
+            location = almanac.humidity_to_location.destination(location);
 
-        for seed_range_pair in seed_range_pairs {
-            binary_search(&almanac, &seed_range_pair, &mut lowest_location);
+            if location < lowest_location {
+                lowest_location = location;
+            }
         }
+    }
 
-        // assert_eq!(EXPECTED_ANSWER, lowest_location);
+    // TODO: the trick seems to be to use reverse lookups since location is the important value, not the seed.
 
-        println!("{}", lowest_location);
+    for seed_range_pair in seed_range_pairs {
+        binary_search(almanac, &seed_range_pair, &mut lowest_location);
     }
+
+    return Ok(lowest_location);
 }
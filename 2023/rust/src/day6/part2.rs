@@ -20,75 +20,52 @@ How many ways can you beat the record in this one much longer race?
 
 */
 
-use crate::io_utils::{extract_numbers_from_line, read_lines_fully};
+use crate::checked;
+use crate::error::{Error, Result};
 
-#[derive(Debug)]
-struct RaceData {
-    time: u64,
-    record_distance: u64,
-}
-
-const EXPECTED_ANSWER: u64 = 27340847;
-
-fn parse_race_data() -> RaceData {
-    let lines = read_lines_fully("src/day6/input.txt");
-
-    assert_eq!(lines.len(), 2);
-
-    let line_times = lines.first().unwrap();
-    let line_distances = lines.last().unwrap();
-
-    let times = extract_numbers_from_line::<u64>(line_times);
-    let distances = extract_numbers_from_line::<u64>(line_distances);
+use super::utils::RaceSheet;
 
-    let the_time = times.into_iter().map(|digit| digit.to_string()).collect::<String>().parse().unwrap();
-    let the_distance = distances.into_iter().map(|digit| digit.to_string()).collect::<String>().parse().unwrap();
+pub fn solve(race_sheet: &RaceSheet) -> Result<u64> {
+    let race_data_item = &race_sheet.single_race;
 
-    return RaceData {
-        time: the_time,
-        record_distance: the_distance
-    };
-}
-
-pub fn run() {
-    let race_data_item = parse_race_data();
-
-    println!("{:?}", race_data_item);
+    verbose!("{:?}", race_data_item);
 
     let mut winning_race_variations = 1_u64;
 
-    let mut button_hold_time_from_start = 1;
+    // only holding for 1 up to the race time moves the boat, so a race nobody can win ends there
+    let mut earliest_hold_time = Option::<u64>::None;
 
-    loop {
-        let distance_start = button_hold_time_from_start * (race_data_item.time - button_hold_time_from_start);
+    for button_hold_time in 1..=race_data_item.time {
+        let time_left = checked::sub(race_data_item.time, button_hold_time, "time left")?;
+        let distance_start = checked::mul(button_hold_time, time_left, "distance")?;
 
         if distance_start > race_data_item.record_distance {
+            earliest_hold_time = Some(button_hold_time);
             break;
         }
-
-        button_hold_time_from_start += 1;
     }
 
+    let button_hold_time_from_start =
+        earliest_hold_time.ok_or_else(|| Error::new("No hold time beats the record"))?;
     let mut button_hold_time_from_end = race_data_item.time;
 
-    loop {
-        let distance_end = button_hold_time_from_end * (race_data_item.time - button_hold_time_from_end);
+    for button_hold_time in (button_hold_time_from_start..=race_data_item.time).rev() {
+        let time_left = checked::sub(race_data_item.time, button_hold_time, "time left")?;
+        let distance_end = checked::mul(button_hold_time, time_left, "distance")?;
 
         if distance_end > race_data_item.record_distance {
+            button_hold_time_from_end = button_hold_time;
             break;
         }
-
-        button_hold_time_from_end -= 1;
     }
 
-    let winning_variations = (button_hold_time_from_end - button_hold_time_from_start + 1) as u64;
-
-    winning_race_variations *= winning_variations;
+    let hold_time_range = checked::sub(button_hold_time_from_end, button_hold_time_from_start, "ways to win")?;
+    let winning_variations = checked::add(hold_time_range, 1, "ways to win")?;
 
-    println!("earliest - {}, latest - {}", button_hold_time_from_start, button_hold_time_from_end);
-    println!("Variations: {}", winning_variations);
+    winning_race_variations = checked::mul(winning_race_variations, winning_variations, "ways to win")?;
 
-    assert_eq!(EXPECTED_ANSWER, winning_race_variations);
+    verbose!("earliest - {}, latest - {}", button_hold_time_from_start, button_hold_time_from_end);
+    verbose!("Variations: {}", winning_variations);
 
-    println!("Total winning variations: {}", winning_race_variations);
+    return Ok(winning_race_variations);
 }
@@ -0,0 +1,97 @@
+use crate::error::{Error, Result};
+use crate::io_utils::parse_labelled_numbers;
+
+const LABELS: [&str; 2] = ["Time", "Distance"];
+
+#[derive(Debug)]
+pub struct RaceData<T> {
+    pub time: T,
+    pub record_distance: T,
+}
+
+/// The sheet of races, read both as the races it lists and as the single race it lists once
+/// the spaces between the digits are ignored
+#[derive(Debug)]
+pub struct RaceSheet {
+    pub races: Vec<RaceData<u16>>,
+    pub single_race: RaceData<u64>,
+}
+
+/// Joins the digits of the numbers into a single number
+fn join_digits(line_ix: usize, line: &str, numbers: &[u16], what: &str) -> Result<u64> {
+    return numbers
+        .iter()
+        .map(|number| number.to_string())
+        .collect::<String>()
+        .parse()
+        .map_err(|_| Error::on_line(line_ix, line, format!("{} is missing or does not fit in a u64", what)));
+}
+
+pub fn parse_race_sheet(input: &str) -> Result<RaceSheet> {
+    let lines = input.lines().collect::<Vec<_>>();
+
+    let [line_times, line_distances] = &lines[..] else {
+        return Err(Error::new(format!("Expected a line of times and a line of distances, found {} lines", lines.len())));
+    };
+
+    let times = parse_labelled_numbers::<u16>(0, line_times, "Time")?;
+    let distances = parse_labelled_numbers::<u16>(1, line_distances, "Distance")?;
+
+    if times.len() != distances.len() {
+        return Err(Error::on_line(
+            1,
+            line_distances,
+            format!("Found {} distances for {} times", distances.len(), times.len()),
+        ));
+    }
+
+    let races = times
+        .iter()
+        .zip(distances.iter())
+        .map(|item| RaceData {
+            time: item.0.to_owned(),
+            record_distance: item.1.to_owned(),
+        })
+        .collect();
+
+    let single_race = RaceData {
+        time: join_digits(0, line_times, &times, "Time")?,
+        record_distance: join_digits(1, line_distances, &distances, "Distance")?,
+    };
+
+    return Ok(RaceSheet { races, single_race });
+}
+
+/// Checks for a line of times followed by a line of as many distances
+pub fn lint(input: &str) -> Vec<Error> {
+    let count_numbers = |line_ix: usize, line: &str| -> Result<usize> {
+        let label = LABELS
+            .get(line_ix)
+            .ok_or_else(|| Error::on_line(line_ix, line, "Expected only a line of times and a line of distances"))?;
+
+        return Ok(parse_labelled_numbers::<u64>(line_ix, line, label)?.len());
+    };
+
+    let lines = input.lines().collect::<Vec<_>>();
+    let counts = lines
+        .iter()
+        .enumerate()
+        .map(|(line_ix, line)| count_numbers(line_ix, line))
+        .collect::<Vec<_>>();
+
+    let mut errors = Vec::<Error>::new();
+
+    match &counts[..] {
+        [] | [_] => errors.push(Error::new("Expected a line of times and a line of distances")),
+        [Ok(times), Ok(distances), ..] if times != distances => errors.push(Error::on_line(
+            1,
+            lines[1],
+            format!("Found {} distances for {} times", distances, times),
+        )),
+        _ => {}
+    }
+
+    errors.extend(counts.into_iter().filter_map(|count| count.err()));
+
+    return errors;
+}
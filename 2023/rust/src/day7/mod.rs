@@ -1,2 +1,47 @@
+use crate::error::{Error, Result};
+use crate::solution::{solve_input, Answer, Sample, Solution};
+
 pub mod part1;
-pub mod part2;
\ No newline at end of file
+pub mod part2;
+pub mod utils;
+
+pub struct Day7;
+
+/// Parses the puzzle input and solves part 1 of it
+pub fn part1(input: &str) -> Result<Answer> {
+    return solve_input(&Day7, 1, input);
+}
+
+/// Parses the puzzle input and solves part 2 of it
+pub fn part2(input: &str) -> Result<Answer> {
+    return solve_input(&Day7, 2, input);
+}
+
+impl Solution for Day7 {
+    type Input = Vec<String>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    const DAY: u8 = 7;
+
+    const SAMPLES: &'static [Sample] = &[
+        Sample { part: 1, file: "input_sample.txt" },
+        Sample { part: 2, file: "input_sample.txt" },
+    ];
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        return Ok(input.lines().map(|line| line.to_owned()).collect());
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Part1> {
+        return part1::solve(input);
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Part2> {
+        return part2::solve(input);
+    }
+
+    fn lint(&self, input: &str) -> Vec<Error> {
+        return utils::lint(input);
+    }
+}
@@ -71,7 +71,9 @@ QQQJA 483 - rank 5
 
 use std::{collections::HashMap, cmp::Ordering};
 
-use crate::io_utils::read_lines_fully;
+use crate::error::Result;
+
+use super::utils::parse_hand_line;
 
 type CardRankingMap = HashMap<char, u8>;
 
@@ -118,9 +120,9 @@ impl Hand {
         }
 
         if distinct_cards_in_hand_set == 3 {
-            if let Some(_) = hand_set.iter().find(|item| item.1 == &3) {
+            if hand_set.values().any(|count| count == &3) {
                 return HandType::ThreeOfAKind;
-            } else if let Some(_) = hand_set.iter().find(|item| item.1 == &2) {
+            } else if hand_set.values().any(|count| count == &2) {
                 return HandType::TwoPair;
             }
         }
@@ -161,28 +163,21 @@ impl Hand {
     }
 }
 
-fn parse_hands() -> Vec<Hand> {
-    let lines = read_lines_fully("src/day7/input.txt");
-
+fn parse_hands(lines: &Vec<String>) -> Result<Vec<Hand>> {
     let mut hands = Vec::<Hand>::new();
 
-    for line in lines {
-        let split = line.split_whitespace().collect::<Vec<&str>>();
-
-        let the_hand = split.first().unwrap();
-        let the_bid = split.last().unwrap().parse::<u16>().unwrap();
+    for (line_ix, line) in lines.iter().enumerate() {
+        let (the_hand, the_bid) = parse_hand_line(line_ix, line)?;
 
         hands.push(Hand::new(the_hand.to_string(), the_bid));
     }
 
     hands.sort_by(|a, b| b.hand_type.cmp(&a.hand_type));
 
-    return hands;
+    return Ok(hands);
 }
 
-const EXPECTED_ANSWER: u32 = 250370104;
-
-pub fn run() {
+pub fn solve(lines: &Vec<String>) -> Result<u32> {
     let mut card_ranking_map = CardRankingMap::new();
     card_ranking_map.insert('A', 14);
     card_ranking_map.insert('K', 13);
@@ -198,7 +193,7 @@ pub fn run() {
     card_ranking_map.insert('3', 3);
     card_ranking_map.insert('2', 2);
 
-    let mut hands = parse_hands();
+    let mut hands = parse_hands(lines)?;
 
     hands.sort_by(|a, b| {
         let hand_type_cmp_result = b.hand_type.cmp(&a.hand_type);
@@ -225,14 +220,12 @@ pub fn run() {
         return std::cmp::Ordering::Equal;
     });
 
-    hands.iter().for_each(|hand| println!("{:?}", hand));
+    hands.iter().for_each(|hand| trace!("{:?}", hand));
 
     let mut total_winnings: u32 = 0;
     hands.iter().enumerate().for_each(|(ix, hand)| {
         total_winnings += hand.bid as u32 * (ix as u32 + 1);
     });
 
-    assert_eq!(EXPECTED_ANSWER, total_winnings);
-
-    println!("Total winnigs: {}", total_winnings);
+    return Ok(total_winnings);
 }
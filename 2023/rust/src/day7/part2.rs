@@ -27,7 +27,9 @@ Using the new joker rule, find the rank of every hand in your set. What are the
 
 use std::{cmp::Ordering, collections::HashMap};
 
-use crate::io_utils::read_lines_fully;
+use crate::error::Result;
+
+use super::utils::parse_hand_line;
 
 type CardRankingTuple = (char, u8);
 type CardRankingMap = HashMap<char, u8>;
@@ -79,9 +81,9 @@ impl Hand {
             if have_jokers {
                 return HandType::FiveOfAKind;
             } else {
-                if let Some(_) = hand_set.iter().find(|item| item.1 == &4) {
+                if hand_set.values().any(|count| count == &4) {
                     return HandType::FourOfAKind;
-                } else if let Some(_) = hand_set.iter().find(|item| item.1 == &2) {
+                } else if hand_set.values().any(|count| count == &2) {
                     return HandType::FullHouse;
                 }
             }
@@ -109,9 +111,9 @@ impl Hand {
 
                 return HandType::FourOfAKind;
             } else {
-                if let Some(_) = hand_set.iter().find(|item| item.1 == &3) {
+                if hand_set.values().any(|count| count == &3) {
                     return HandType::ThreeOfAKind;
-                } else if let Some(_) = hand_set.iter().find(|item| item.1 == &2) {
+                } else if hand_set.values().any(|count| count == &2) {
                     return HandType::TwoPair;
                 }
             }
@@ -172,28 +174,21 @@ impl Hand {
     }
 }
 
-fn parse_hands() -> Vec<Hand> {
-    let lines = read_lines_fully("src/day7/input.txt");
-
+fn parse_hands(lines: &Vec<String>) -> Result<Vec<Hand>> {
     let mut hands = Vec::<Hand>::new();
 
-    for line in lines {
-        let split = line.split_whitespace().collect::<Vec<&str>>();
-
-        let the_hand = split.first().unwrap();
-        let the_bid = split.last().unwrap().parse::<u16>().unwrap();
+    for (line_ix, line) in lines.iter().enumerate() {
+        let (the_hand, the_bid) = parse_hand_line(line_ix, line)?;
 
         hands.push(Hand::new(the_hand.to_string(), the_bid));
     }
 
     hands.sort_by(|a, b| b.hand_type.cmp(&a.hand_type));
 
-    return hands;
+    return Ok(hands);
 }
 
-const EXPECTED_ANSWER: u32 = 251735672;
-
-pub fn run() {
+pub fn solve(lines: &Vec<String>) -> Result<u32> {
     let mut card_ranking_map = CardRankingMap::new();
     card_ranking_map.insert('A', 14);
     card_ranking_map.insert('K', 13);
@@ -209,7 +204,7 @@ pub fn run() {
     card_ranking_map.insert('2', 2);
     card_ranking_map.insert('J', 1);
 
-    let mut hands = parse_hands();
+    let mut hands = parse_hands(lines)?;
 
     hands.sort_by(|a, b| {
         let hand_type_cmp_result = b.hand_type.cmp(&a.hand_type);
@@ -243,7 +238,5 @@ pub fn run() {
         total_winnings += hand.bid as u32 * (ix as u32 + 1);
     });
 
-    assert_eq!(EXPECTED_ANSWER, total_winnings);
-
-    println!("Total winnigs: {}", total_winnings);
+    return Ok(total_winnings);
 }
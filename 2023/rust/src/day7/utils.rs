@@ -0,0 +1,52 @@
+use crate::error::{Error, Result};
+use crate::io_utils::lint_lines;
+
+pub const CARDS_IN_HAND: usize = 5;
+const CARD_LABELS: &str = "AKQJT98765432";
+
+/// Splits a `<hand> <bid>` line, checking the hand is made of 5 known cards
+pub fn parse_hand_line(line_ix: usize, line: &str) -> Result<(&str, u16)> {
+    let split = line.split_whitespace().collect::<Vec<&str>>();
+
+    let [the_hand, the_bid] = split[..] else {
+        return Err(Error::on_line(line_ix, line, "Expected a hand and a bid"));
+    };
+
+    if let Some(unknown_card) = the_hand.matches(|card| !CARD_LABELS.contains(card)).next() {
+        return Err(Error::at(line_ix, line, unknown_card, "Unknown card"));
+    }
+
+    if the_hand.len() != CARDS_IN_HAND {
+        return Err(Error::at(
+            line_ix,
+            line,
+            the_hand,
+            format!("Expected a hand of {} cards, found {}", CARDS_IN_HAND, the_hand.len()),
+        ));
+    }
+
+    let the_bid = the_bid
+        .parse::<u16>()
+        .map_err(|_| Error::at(line_ix, line, the_bid, "Bid is not a number"))?;
+
+    return Ok((the_hand, the_bid));
+}
+
+pub fn lint(input: &str) -> Vec<Error> {
+    return lint_lines(input, |line_ix, line| parse_hand_line(line_ix, line).map(|_| ()));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::lint;
+
+    #[test]
+    fn lint_points_at_hands_that_are_not_five_cards() {
+        let errors = lint("32T3K 765\nT55J5J 684\nKK677 28\nQQQJ 483\n");
+
+        let positions = errors.iter().map(|error| (error.line, error.column)).collect::<Vec<_>>();
+
+        assert_eq!(positions, [(Some(2), Some(1)), (Some(4), Some(1))]);
+        assert_eq!(errors[0].message, "Expected a hand of 5 cards, found 6");
+    }
+}
@@ -36,75 +36,37 @@ Starting at AAA, follow the left/right instructions. How many steps are required
 
 */
 
-use std::collections::HashMap;
+use crate::cancel;
+use crate::error::{Error, Result};
 
-use crate::io_utils::read_lines_fully;
+use super::utils::{Network, Node};
 
-const EXPECTED_ANSWER: u32 = 13771;
+fn starting_point(network: &Network) -> Result<Node> {
+    verbose!("{}", network.directions);
 
-fn parse() -> (String, (String, (String, String)), HashMap<String, (String, String)>) {
-    let lines = read_lines_fully("src/day8/input.txt")
-        .into_iter()
-        .filter(|line| !line.is_empty())
-        .collect::<Vec<_>>();
+    let key_directions = network
+        .node_map
+        .get("AAA")
+        .ok_or_else(|| Error::new("No node named AAA to start from"))?;
 
-    let mut iterator = lines.iter();
-
-    let directions = iterator.next().unwrap();
-
-    println!("{}", directions);
-
-    let mut directional_map = HashMap::<String, (String, String)>::new();
-
-    let mut starting_point = None::<(String, (String, String))>;
-
-    for (ix, mapping_line) in iterator.enumerate() {
-        let split = mapping_line.split(" = ").collect::<Vec<_>>();
-
-        let direction_key = split.first().unwrap();
-        let key_directions = split
-            .last()
-            .unwrap()
-            .trim_matches(|p| p == '(' || p == ')')
-            .split(',')
-            .map(|item| item.trim())
-            .collect::<Vec<_>>();
-
-        if direction_key == &"AAA" {
-            starting_point = Some(
-                (
-                    direction_key.to_string(), 
-                    (
-                        key_directions.first().unwrap().to_string(),
-                        key_directions.last().unwrap().to_string(),
-                    )
-                )
-            )
-        }
-
-        directional_map.insert(
-            direction_key.to_string(),
-            (
-                key_directions.first().unwrap().to_string(),
-                key_directions.last().unwrap().to_string(),
-            ),
-        );
-    }
-
-    return (directions.to_string(), starting_point.unwrap(), directional_map);
+    return Ok((String::from("AAA"), key_directions.clone()));
 }
 
-pub fn run() {
-    let directions = parse();
+pub fn solve(network: &Network) -> Result<u32> {
+    let starting_point = starting_point(network)?;
 
     let mut walking = true;
     let mut steps_taken = 0;
-    let mut current_stop = directions.1; 
+    let mut current_stop = starting_point;
 
     let mut full_pattern_iteration_attempts = 0;
 
+    let cancel = cancel::current();
+
     while walking {
-        for direction in directions.0.chars() { 
+        cancel.checkpoint();
+
+        for direction in network.directions.chars() { 
             steps_taken += 1;
 
             // println!("Curr stop: {:?}", current_stop);
@@ -115,7 +77,7 @@ pub fn run() {
                     break;
                 }
 
-                current_stop = (current_stop.1.0.clone(), directions.2.get(&current_stop.1.0).unwrap().clone());
+                current_stop = (current_stop.1.0.clone(), network.node_map.get(&current_stop.1.0).unwrap().clone());
             } else {
                 if current_stop.1.1 == "ZZZ" {
                     walking = false;
@@ -123,7 +85,7 @@ pub fn run() {
                 }
 
                 // right
-                current_stop = (current_stop.1.1.clone(), directions.2.get(&current_stop.1.1).unwrap().clone());
+                current_stop = (current_stop.1.1.clone(), network.node_map.get(&current_stop.1.1).unwrap().clone());
             }
         }
 
@@ -132,10 +94,8 @@ pub fn run() {
 
         
 
-        println!("Walked entire pattern with no exit for iteration: {}", full_pattern_iteration_attempts);
+        verbose!("Walked entire pattern with no exit for iteration: {}", full_pattern_iteration_attempts);
     }
 
-    assert_eq!(EXPECTED_ANSWER, steps_taken);
-
-    println!("Steps taken for: {} -> {}", directions.0, steps_taken);
+    return Ok(steps_taken);
 }
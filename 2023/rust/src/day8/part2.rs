@@ -38,79 +38,39 @@ Simultaneously start on every node that ends with A. How many steps does it take
 
 use std::collections::HashMap;
 
-use crate::{io_utils::read_lines_fully, math::calculate_lcm};
+use crate::cancel;
+use crate::error::{Error, Result};
+use crate::math::calculate_lcm;
+
+use super::utils::Network;
 
 type Direction = String;
 type LeftRightDirections = (String, String);
 type StepsTaken = u64;
 type Walking = bool;
 
-const EXPECTED_ANSWER: u64 = 13129439557681;
-
-fn parse() -> (
-    String,
-    Vec<(Direction, LeftRightDirections)>,
-    HashMap<Direction, LeftRightDirections>,
-) {
-    let lines = read_lines_fully("src/day8/input.txt")
-        .into_iter()
-        .filter(|line| !line.is_empty())
+fn starting_points(network: &Network) -> Result<Vec<(Direction, LeftRightDirections)>> {
+    let starting_points = network
+        .nodes
+        .iter()
+        .filter(|(direction_key, _)| direction_key.ends_with('A'))
+        .cloned()
         .collect::<Vec<_>>();
 
-    let mut iterator = lines.iter();
-
-    let directions = iterator.next().unwrap();
-
-    let mut directional_map = HashMap::<Direction, LeftRightDirections>::new();
-
-    let mut starting_points = Vec::<(Direction, LeftRightDirections)>::new();
-
-    for mapping_line in iterator {
-        let split = mapping_line.split(" = ").collect::<Vec<_>>();
-
-        let direction_key = split.first().unwrap();
-        let key_directions = split
-            .last()
-            .unwrap()
-            .trim_matches(|p| p == '(' || p == ')')
-            .split(',')
-            .map(|item| item.trim())
-            .collect::<Vec<_>>();
-
-        if direction_key.ends_with('A') {
-            starting_points.push((
-                direction_key.to_string(),
-                (
-                    key_directions.first().unwrap().to_string(),
-                    key_directions.last().unwrap().to_string(),
-                ),
-            ));
-        }
-
-        directional_map.insert(
-            direction_key.to_string(),
-            (
-                key_directions.first().unwrap().to_string(),
-                key_directions.last().unwrap().to_string(),
-            ),
-        );
+    if starting_points.is_empty() {
+        return Err(Error::new("No nodes ending with A to start from"));
     }
 
-    return (
-        directions.to_string(),
-        starting_points,
-        directional_map,
-    );
+    return Ok(starting_points);
 }
 
-pub fn run() {
-    let directions = parse();
+pub fn solve(network: &Network) -> Result<u64> {
+    let starting_points = starting_points(network)?;
 
     let mut current_stops_for_walk_id = HashMap::<usize, (Direction, LeftRightDirections)>::new();
     let mut walk_id_state = HashMap::<usize, (StepsTaken, Walking)>::new();
 
-    directions
-        .1
+    starting_points
         .iter()
         .enumerate()
         .for_each(|(id, starting_point)| {
@@ -118,30 +78,31 @@ pub fn run() {
             walk_id_state.insert(id, (0, true));
         });
 
-    println!("Directions: {}", directions.0);
-    println!();
-    println!("All starting points: {:?}", current_stops_for_walk_id);
-    println!();
+    verbose!("Directions: {}", network.directions);
+    verbose!("All starting points: {:?}", current_stops_for_walk_id);
 
     let walk_ids = current_stops_for_walk_id
-        .iter()
-        .map(|(walk_id, _)| walk_id.clone())
+        .keys()
+        .copied()
         .collect::<Vec<_>>();
 
+    let cancel = cancel::current();
+
     for walk_id in walk_ids {
         loop {
-            for direction in directions.0.chars() {
+            cancel.checkpoint();
+
+            for direction in network.directions.chars() {
                 let current_stop_point = current_stops_for_walk_id.get(&walk_id).unwrap();
     
                 // println!("walk_id: {}, {:?}", walk_id, current_stop_point);
     
                 if current_stop_point.0.ends_with('Z') {
-                    println!(
+                    verbose!(
                         "Found stop ending with Z for walk id: {}, with steps taken of: {}",
                         walk_id,
                         walk_id_state.get(&walk_id).unwrap().0
                     );
-                    println!();
 
                     walk_id_state.get_mut(&walk_id).unwrap().1 = false;
     
@@ -157,7 +118,7 @@ pub fn run() {
                         walk_id,
                         (
                             current_stop_point.1 .0.clone(),
-                            directions.2.get(&current_stop_point.1 .0).unwrap().clone(),
+                            network.node_map.get(&current_stop_point.1 .0).unwrap().clone(),
                         ),
                     );
                 } else {
@@ -165,7 +126,7 @@ pub fn run() {
                         walk_id,
                         (
                             current_stop_point.1 .1.clone(),
-                            directions.2.get(&current_stop_point.1 .1).unwrap().clone(),
+                            network.node_map.get(&current_stop_point.1 .1).unwrap().clone(),
                         ),
                     );
                 }
@@ -179,9 +140,7 @@ pub fn run() {
         }
     }
 
-    let lcm_steps = calculate_lcm(walk_id_state.iter().map(|item| item.1.0).collect::<Vec<_>>().as_slice());
-
-    assert_eq!(EXPECTED_ANSWER, lcm_steps);
+    let lcm_steps = calculate_lcm(walk_id_state.iter().map(|item| item.1.0).collect::<Vec<_>>().as_slice())?;
 
-    println!("LCM Steps to take: {}",  lcm_steps);
+    return Ok(lcm_steps);
 }
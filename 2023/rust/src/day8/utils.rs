@@ -0,0 +1,95 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::error::{Error, Result};
+
+pub type Node = (String, (String, String));
+
+/// The left/right instructions, and where left and right lead from each node
+#[derive(Debug)]
+pub struct Network {
+    pub directions: String,
+    /// In the order they are listed
+    pub nodes: Vec<Node>,
+    pub node_map: HashMap<String, (String, String)>,
+}
+
+/// Splits an `AAA = (BBB, CCC)` node line into the node and where left and right lead
+fn parse_node_line(line_ix: usize, mapping_line: &str) -> Result<(&str, &str, &str)> {
+    let (direction_key, key_directions) = mapping_line
+        .split_once(" = ")
+        .ok_or_else(|| Error::on_line(line_ix, mapping_line, "Expected '<node> = (<left>, <right>)'"))?;
+
+    let (left, right) = key_directions
+        .strip_prefix('(')
+        .and_then(|rest| rest.strip_suffix(')'))
+        .and_then(|rest| rest.split_once(','))
+        .ok_or_else(|| Error::at(line_ix, mapping_line, key_directions, "Expected '(<left>, <right>)'"))?;
+
+    return Ok((direction_key, left.trim(), right.trim()));
+}
+
+/// Reads what it can of the map, along with every problem found on the way
+fn read_network<S: AsRef<str>>(lines: &[S]) -> (String, Vec<Node>, Vec<Error>) {
+    let mut iterator = lines.iter().map(|line| line.as_ref()).enumerate();
+    let mut errors = Vec::<Error>::new();
+
+    let directions = match iterator.next() {
+        Some((_, directions)) => directions,
+        None => return (String::new(), Vec::new(), vec![Error::new("Map is empty")]),
+    };
+
+    for unknown_direction in directions.matches(|direction| direction != 'L' && direction != 'R') {
+        errors.push(Error::at(0, directions, unknown_direction, "Expected only L and R directions"));
+    }
+
+    let mut nodes = Vec::<Node>::new();
+    let mut references = Vec::<(usize, &str, &str)>::new();
+
+    for (line_ix, mapping_line) in iterator {
+        if mapping_line.is_empty() {
+            continue;
+        }
+
+        let (direction_key, left, right) = match parse_node_line(line_ix, mapping_line) {
+            Ok(node) => node,
+            Err(error) => {
+                errors.push(error);
+                continue;
+            }
+        };
+
+        references.push((line_ix, mapping_line, left));
+        references.push((line_ix, mapping_line, right));
+
+        nodes.push((direction_key.to_string(), (left.to_string(), right.to_string())));
+    }
+
+    let defined = nodes.iter().map(|node| node.0.as_str()).collect::<HashSet<_>>();
+
+    for (line_ix, mapping_line, reference) in references {
+        if !defined.contains(reference) {
+            errors.push(Error::at(line_ix, mapping_line, reference, "Node is not defined"));
+        }
+    }
+
+    return (directions.to_string(), nodes, errors);
+}
+
+/// Parses the left/right instructions and the `AAA = (BBB, CCC)` node lines, checking that
+/// every node that is pointed to is also defined
+pub fn parse_network(input: &str) -> Result<Network> {
+    // blank lines are kept so that errors point at the right line
+    let (directions, nodes, errors) = read_network(&input.lines().collect::<Vec<_>>());
+
+    if let Some(error) = errors.into_iter().next() {
+        return Err(error);
+    }
+
+    let node_map = nodes.iter().cloned().collect::<HashMap<_, _>>();
+
+    return Ok(Network { directions, nodes, node_map });
+}
+
+pub fn lint(input: &str) -> Vec<Error> {
+    return read_network(&input.lines().collect::<Vec<_>>()).2;
+}
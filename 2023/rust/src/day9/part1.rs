@@ -77,24 +77,18 @@ Analyze your OASIS report and extrapolate the next value for each history. What
 
 */
 
-use crate::day9::utils::{parse, build_history_sequence, predict_next_number, NumberPredictionType};
-
-const EXPECTED_ANSWER: i32 = 1581679977;
-
-pub fn run() {
-    let number_lines = parse();
+use crate::day9::utils::{build_history_sequence, predict_next_number, NumberPredictionType};
 
+pub fn solve(number_lines: &Vec<Vec<i32>>) -> i32 {
     let mut total = 0;
 
     for number_line in number_lines {
         let mut history = Vec::<Vec<i32>>::new();
-        history.push(number_line);
+        history.push(number_line.clone());
 
         build_history_sequence(&mut history);
         total += predict_next_number(&history, NumberPredictionType::Next);
     }
 
-    assert_eq!(EXPECTED_ANSWER, total);
-
-    println!("Total - {}", total);
+    return total;
 }
@@ -1,4 +1,5 @@
-use crate::io_utils::{read_lines_fully, extract_numbers_from_line};
+use crate::error::{Error, Result};
+use crate::io_utils::{lint_lines, parse_numbers};
 
 
 #[derive(PartialEq)]
@@ -7,13 +8,28 @@ pub enum NumberPredictionType {
     Next
 }
 
-pub fn parse() -> Vec<Vec<i32>> {
-    let number_lines = read_lines_fully("src/day9/input.txt")
-        .iter()
-        .map(|line| extract_numbers_from_line::<i32>(line))
-        .collect::<Vec<_>>();
+fn parse_history(line_ix: usize, line: &str) -> Result<Vec<i32>> {
+    let numbers = parse_numbers::<i32>(line_ix, line, line)?;
 
-    number_lines
+    if numbers.is_empty() {
+        return Err(Error::on_line(line_ix, line, "Expected a history of at least one number"));
+    }
+
+    return Ok(numbers);
+}
+
+pub fn parse(input: &str) -> Result<Vec<Vec<i32>>> {
+    let mut number_lines = Vec::<Vec<i32>>::new();
+
+    for (line_ix, line) in input.lines().enumerate() {
+        number_lines.push(parse_history(line_ix, line)?);
+    }
+
+    return Ok(number_lines);
+}
+
+pub fn lint(input: &str) -> Vec<Error> {
+    return lint_lines(input, |line_ix, line| parse_history(line_ix, line).map(|_| ()));
 }
 
 pub fn build_history_sequence(number_history: &mut Vec<Vec<i32>>) {
@@ -27,8 +43,8 @@ pub fn build_history_sequence(number_history: &mut Vec<Vec<i32>>) {
     let iterations = last.len() - 1;
 
     for ix in 0..iterations {
-        let first = last.iter().nth(ix).unwrap();
-        let second = last.iter().nth(ix + 1).unwrap();
+        let first = last.get(ix).unwrap();
+        let second = last.get(ix + 1).unwrap();
 
         diff_at_depth.push(second - first);
     }
@@ -0,0 +1,103 @@
+use std::fmt;
+
+/// An input that a day could not parse or solve, with as much context as is known.
+///
+/// Parsers and solvers fill in the line, column and offending text; the day and part are
+/// added by [`crate::solution::Puzzle`], so days do not have to repeat them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Error {
+    pub day: Option<u8>,
+    /// Not set for errors in parsing, which is shared by both parts
+    pub part: Option<u8>,
+    /// Starting at 1
+    pub line: Option<usize>,
+    /// Starting at 1, counted in characters
+    pub column: Option<usize>,
+    pub text: Option<String>,
+    pub message: String,
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Error {
+    /// An error about the input as a whole rather than a specific place in it
+    pub fn new(message: impl Into<String>) -> Error {
+        return Error {
+            day: None,
+            part: None,
+            line: None,
+            column: None,
+            text: None,
+            message: message.into(),
+        };
+    }
+
+    /// An error about `fragment`, found on line `line_ix` (starting at 0) of the input.
+    /// The column is worked out from where `fragment` sits in `line`, so it should be a
+    /// slice of it.
+    pub fn at(line_ix: usize, line: &str, fragment: &str, message: impl Into<String>) -> Error {
+        let line_start = line.as_ptr() as usize;
+        let fragment_start = fragment.as_ptr() as usize;
+
+        let offset = if fragment_start >= line_start && fragment_start + fragment.len() <= line_start + line.len() {
+            Some(fragment_start - line_start)
+        } else {
+            line.find(fragment)
+        };
+
+        let column = offset.map_or(1, |offset| line[..offset].chars().count() + 1);
+
+        return Error {
+            line: Some(line_ix + 1),
+            column: Some(column),
+            text: Some(fragment.to_owned()),
+            ..Error::new(message)
+        };
+    }
+
+    /// An error about line `line_ix` (starting at 0) as a whole
+    pub fn on_line(line_ix: usize, line: &str, message: impl Into<String>) -> Error {
+        return Error::at(line_ix, line, line, message);
+    }
+
+    pub fn in_day(self, day: u8) -> Error {
+        return Error { day: self.day.or(Some(day)), ..self };
+    }
+
+    pub fn in_part(self, part: u8) -> Error {
+        return Error { part: self.part.or(Some(part)), ..self };
+    }
+
+    /// The same error without its day and part, for output that already names them
+    pub fn in_input(&self) -> Error {
+        return Error { day: None, part: None, ..self.clone() };
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let context = [
+            self.day.map(|day| format!("day {}", day)),
+            self.part.map(|part| format!("part {}", part)),
+            self.line.map(|line| format!("line {}", line)),
+            self.column.map(|column| format!("column {}", column)),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+
+        if !context.is_empty() {
+            write!(f, "{}: ", context.join(", "))?;
+        }
+
+        write!(f, "{}", self.message)?;
+
+        if let Some(text) = &self.text {
+            write!(f, " in '{}'", text)?;
+        }
+
+        return Ok(());
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,201 @@
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::path::PathBuf;
+
+use crate::io_utils::normalise_input;
+
+/// Environment variable that overrides the default inputs directory
+pub const INPUTS_DIR_ENV: &str = "AOC_INPUTS_DIR";
+
+/// Calendar year, used to keep inputs of other years apart in the shared inputs directory
+pub const YEAR: u16 = 2023;
+
+/// Where a day's puzzle input is read from
+#[derive(Debug, Clone, PartialEq)]
+pub enum InputSource {
+    /// A single file, used as is for whichever day is run
+    File(PathBuf),
+    /// Standard input, read once until EOF
+    Stdin,
+    /// A directory holding `day05.txt`, or one directory per day, i.e. `<dir>/day5/input.txt`
+    Directory(PathBuf),
+    /// The user's inputs directory, or for the days it has no input for, `inputs/` in the
+    /// crate directory
+    Auto,
+}
+
+/// The user's configuration directory, `~/.config` unless `XDG_CONFIG_HOME` points elsewhere
+pub fn config_dir() -> Option<PathBuf> {
+    return match env::var_os("XDG_CONFIG_HOME").filter(|dir| !dir.is_empty()) {
+        Some(dir) => Some(PathBuf::from(dir)),
+        None => Some(PathBuf::from(env::var_os("HOME").filter(|dir| !dir.is_empty())?).join(".config")),
+    };
+}
+
+/// The inputs directory outside the repository, `~/.config/aoc/inputs/2023`
+pub fn user_inputs_dir() -> Option<PathBuf> {
+    return Some(config_dir()?.join("aoc").join("inputs").join(YEAR.to_string()));
+}
+
+/// The git-ignored inputs directory in the crate directory, for inputs kept next to the code
+pub fn local_inputs_dir() -> PathBuf {
+    return PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("inputs");
+}
+
+/// The samples committed alongside each day in the crate's `src` directory. Puzzle inputs are
+/// not committed, as they may not be shared.
+pub fn bundled_inputs_dir() -> PathBuf {
+    return PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("src");
+}
+
+/// The day's input in `dir`, as `day05.txt` or else as `day5/input.txt`. Falls back to the
+/// first when neither exists, so that errors name the layout the user is most likely after.
+fn day_input_in(dir: &PathBuf, day: u8) -> (PathBuf, bool) {
+    let flat = dir.join(format!("day{:02}.txt", day));
+    let nested = dir.join(format!("day{}", day)).join("input.txt");
+
+    if flat.is_file() {
+        return (flat, true);
+    }
+
+    if nested.is_file() {
+        return (nested, true);
+    }
+
+    return (flat, false);
+}
+
+/// The day's input in the first of `dirs` that has it, or when none does, the path it was
+/// expected at in the first of them
+fn day_input_in_any(dirs: &[PathBuf], day: u8) -> Option<PathBuf> {
+    let candidates = dirs.iter().map(|dir| day_input_in(dir, day)).collect::<Vec<_>>();
+    let found = candidates.iter().find(|(_, exists)| *exists).or(candidates.first());
+
+    return found.map(|(path, _)| path.clone());
+}
+
+impl InputSource {
+    pub fn path(&self, day: u8) -> Option<PathBuf> {
+        return match self {
+            InputSource::File(path) => Some(path.clone()),
+            InputSource::Stdin => None,
+            InputSource::Directory(dir) => Some(day_input_in(dir, day).0),
+            InputSource::Auto => {
+                // errors name the user's inputs directory when the input is in neither
+                let dirs = user_inputs_dir().into_iter().chain([local_inputs_dir()]).collect::<Vec<_>>();
+
+                day_input_in_any(&dirs, day)
+            }
+        };
+    }
+
+    pub fn describe(&self, day: u8) -> String {
+        return match self.path(day) {
+            Some(path) => path.display().to_string(),
+            None => String::from("<stdin>"),
+        };
+    }
+
+    /// Reads the input, normalised so that line endings and stray whitespace from the
+    /// editor it was saved with do not reach the parsers
+    pub fn read(&self, day: u8) -> io::Result<String> {
+        let raw_input = match self.path(day) {
+            Some(path) => fs::read_to_string(path)?,
+            None => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+
+                input
+            }
+        };
+
+        return Ok(normalise_input(&raw_input));
+    }
+}
+
+/// `AOC_INPUTS_DIR` when it is set, and otherwise the user's inputs directory falling back
+/// to `inputs/` in the crate directory
+impl Default for InputSource {
+    fn default() -> Self {
+        return match env::var_os(INPUTS_DIR_ENV).filter(|dir| !dir.is_empty()) {
+            Some(dir) => InputSource::Directory(PathBuf::from(dir)),
+            None => InputSource::Auto,
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+    use std::path::PathBuf;
+    use std::process;
+
+    use super::{day_input_in, day_input_in_any};
+
+    /// A fresh directory in the temp directory that is unique to this test run
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-2023-input-{}-{}", process::id(), name));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+
+        return dir;
+    }
+
+    fn add_nested_input(dir: &PathBuf, day: u8) -> PathBuf {
+        let path = dir.join(format!("day{}", day)).join("input.txt");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "").unwrap();
+
+        return path;
+    }
+
+    #[test]
+    fn flat_input_comes_before_nested_input() {
+        let dir = temp_dir("flat");
+        add_nested_input(&dir, 5);
+        fs::write(dir.join("day05.txt"), "").unwrap();
+
+        let found = day_input_in(&dir, 5);
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(found, (dir.join("day05.txt"), true));
+    }
+
+    #[test]
+    fn nested_input_is_used_without_a_flat_one() {
+        let dir = temp_dir("nested");
+        let nested = add_nested_input(&dir, 5);
+
+        let found = day_input_in(&dir, 5);
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(found, (nested, true));
+    }
+
+    #[test]
+    fn missing_input_is_expected_as_a_flat_file() {
+        let dir = temp_dir("missing");
+
+        let found = day_input_in(&dir, 5);
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(found, (dir.join("day05.txt"), false));
+    }
+
+    #[test]
+    fn input_is_taken_from_the_first_directory_that_has_it() {
+        let user_dir = temp_dir("user");
+        let local_dir = temp_dir("local");
+        let nested = add_nested_input(&local_dir, 5);
+        fs::write(user_dir.join("day06.txt"), "").unwrap();
+
+        let dirs = [user_dir.clone(), local_dir.clone()];
+        let found = [day_input_in_any(&dirs, 5), day_input_in_any(&dirs, 6), day_input_in_any(&dirs, 7)];
+        fs::remove_dir_all(&user_dir).unwrap();
+        fs::remove_dir_all(&local_dir).unwrap();
+
+        assert_eq!(found, [Some(nested), Some(user_dir.join("day06.txt")), Some(user_dir.join("day07.txt"))]);
+    }
+}
@@ -1,37 +1,167 @@
-use std::fs::File;
-use std::io::{self, BufRead};
-use std::path::Path;
 use std::str::FromStr;
 
-use num_traits::{FromPrimitive, Num};
+use crate::error::{Error, Result};
 
-pub fn read_lines<P>(filename: P) -> io::Result<io::Lines<io::BufReader<File>>>
+const BYTE_ORDER_MARK: char = '\u{feff}';
+
+/// Brings an input into the shape the parsers expect, whichever editor or OS it was saved
+/// with: no byte order mark, `\n` line endings, no trailing whitespace on any line and no
+/// blank lines at the end. Blank lines between sections are kept.
+pub fn normalise_input(raw_input: &str) -> String {
+    let without_bom = raw_input.strip_prefix(BYTE_ORDER_MARK).unwrap_or(raw_input);
+
+    // `lines` already drops the `\r` of a `\r\n` ending, trimming catches a stray one
+    let mut lines = without_bom.lines().map(|line| line.trim_end()).collect::<Vec<_>>();
+
+    while lines.last().is_some_and(|line| line.is_empty()) {
+        lines.pop();
+    }
+
+    let mut normalised = lines.join("\n");
+
+    if !normalised.is_empty() {
+        normalised.push('\n');
+    }
+
+    return normalised;
+}
+
+/// Parses every whitespace separated number in `fragment`, which is part of line `line_ix`
+/// (starting at 0) of the input. A token that is not a number is an error.
+pub fn parse_numbers<T>(line_ix: usize, line: &str, fragment: &str) -> Result<Vec<T>>
 where
-    P: AsRef<Path>,
+    T: FromStr
 {
-    let file = File::open(filename)?;
-    Ok(io::BufReader::new(file).lines())
+    return fragment
+        .split_whitespace()
+        .map(|item| item.parse::<T>().map_err(|_| Error::at(line_ix, line, item, "Expected a number")))
+        .collect();
+}
+
+/// Checks that every line of a grid input is as wide as the first one
+pub fn check_grid(input: &str) -> Result<()> {
+    return match grid_errors(input).into_iter().next() {
+        Some(error) => Err(error),
+        None => Ok(()),
+    };
 }
 
-pub fn read_lines_fully(filename: &str) -> Vec<String> {
-    let mut the_lines = Vec::<String>::new();
+/// Every line of a grid input that is not as wide as the first one
+pub fn grid_errors(input: &str) -> Vec<Error> {
+    let mut lines = input.lines().enumerate();
 
-    let line_read_result = read_lines(filename);
+    let width = match lines.next() {
+        Some((_, first_line)) => first_line.chars().count(),
+        None => return vec![Error::new("Grid is empty")],
+    };
 
-    if let Ok(lines) = line_read_result {
-        for line in lines {
-            if let Ok(the_line) = line {
-                the_lines.push(the_line);
-            }
+    let mut errors = Vec::<Error>::new();
+
+    for (line_ix, line) in lines {
+        let line_width = line.chars().count();
+
+        if line_width != width {
+            let message = format!("Grid row is {} wide where the first row is {}", line_width, width);
+
+            // point at the extra cells of a long row, or the end of a short one
+            errors.push(match line.char_indices().nth(width) {
+                Some((offset, _)) => Error::at(line_ix, line, &line[offset..], message),
+                None => Error { column: Some(line_width + 1), ..Error::on_line(line_ix, line, message) },
+            });
         }
     }
 
-    return the_lines;
+    return errors;
 }
 
-pub fn extract_numbers_from_line<T>(line: &str) -> Vec<T> 
+/// Runs `check` over every line of the input, collecting the problem it finds on each line
+/// instead of stopping at the first one
+pub fn lint_lines(input: &str, check: impl Fn(usize, &str) -> Result<()>) -> Vec<Error> {
+    return input
+        .lines()
+        .enumerate()
+        .filter_map(|(line_ix, line)| check(line_ix, line).err())
+        .collect();
+}
+
+/// Parses the numbers on a line of the form `<label>: <numbers>`
+pub fn parse_labelled_numbers<T>(line_ix: usize, line: &str, label: &str) -> Result<Vec<T>>
 where
-    T: FromStr + FromPrimitive + Num
+    T: FromStr
 {
-    return line.split_whitespace().filter_map(|item| item.parse::<T>().ok()).collect();
-}
\ No newline at end of file
+    let numbers = line
+        .strip_prefix(label)
+        .and_then(|rest| rest.strip_prefix(':'))
+        .ok_or_else(|| Error::on_line(line_ix, line, format!("Expected the line to start with '{}:'", label)))?;
+
+    return parse_numbers(line_ix, line, numbers);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::normalise_input;
+    use crate::input::bundled_inputs_dir;
+    use crate::registry::SOLUTIONS;
+
+    /// The ways a sample could come out of an editor on another platform
+    fn variants(input: &str) -> Vec<(&'static str, String)> {
+        let crlf = input.replace('\n', "\r\n");
+
+        return vec![
+            ("CRLF", crlf.clone()),
+            ("BOM", format!("\u{feff}{}", input)),
+            ("BOM and CRLF", format!("\u{feff}{}", crlf)),
+            ("trailing whitespace", input.replace('\n', " \t\n")),
+            ("trailing blank lines", format!("{}\n\n\r\n", input)),
+            ("no final newline", input.trim_end_matches('\n').to_owned()),
+        ];
+    }
+
+    #[test]
+    fn normalise_strips_bom_and_line_endings() {
+        assert_eq!(normalise_input("\u{feff}a\r\nb\r\n"), "a\nb\n");
+    }
+
+    #[test]
+    fn normalise_trims_trailing_whitespace_and_blank_lines() {
+        assert_eq!(normalise_input("a  \nb\t\r\n\n \n"), "a\nb\n");
+    }
+
+    #[test]
+    fn normalise_keeps_blank_lines_between_sections() {
+        assert_eq!(normalise_input("seeds: 1\r\n\r\nmap:\r\n1 2 3\r\n"), "seeds: 1\n\nmap:\n1 2 3\n");
+    }
+
+    #[test]
+    fn normalise_keeps_empty_input_empty() {
+        assert_eq!(normalise_input(""), "");
+        assert_eq!(normalise_input("\u{feff}\r\n"), "");
+    }
+
+    #[test]
+    fn samples_solve_the_same_in_every_variant() {
+        for puzzle in SOLUTIONS {
+            for sample in puzzle.samples() {
+                let path = bundled_inputs_dir().join(format!("day{}", puzzle.day())).join(sample.file);
+                let original = normalise_input(&fs::read_to_string(&path).unwrap());
+
+                let solve = |input: &str| {
+                    let parsed = puzzle.parse(input).unwrap();
+
+                    return puzzle.solve(sample.part, parsed.as_ref()).unwrap();
+                };
+
+                let expected = solve(&original);
+
+                for (variant, input) in variants(&original) {
+                    let normalised = normalise_input(&input);
+
+                    assert_eq!(normalised, original, "day {} {} with {}", puzzle.day(), sample.file, variant);
+                    assert_eq!(solve(&normalised), expected, "day {} {} with {}", puzzle.day(), sample.file, variant);
+                }
+            }
+        }
+    }
+}
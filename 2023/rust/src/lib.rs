@@ -1,3 +1,40 @@
+//! Solutions to Advent of Code 2023.
+//!
+//! Each day's module has `part1` and `part2` functions that parse a puzzle input and solve
+//! that part of it, e.g. `aoc_2023::day5::part2(&input)`. To solve both parts from one
+//! parsed input, use the day's [`solution::Solution`] implementation, e.g. `day5::Day5`,
+//! directly. [`registry::SOLUTIONS`] lists every day.
+
+#![allow(dead_code)]
+#![allow(clippy::needless_return, clippy::ptr_arg, clippy::type_complexity)]
+
+#[macro_use]
+pub mod logging;
+
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+pub mod day10;
+pub mod day11;
+
+pub mod answer;
+pub mod cancel;
+pub mod checked;
+pub mod error;
+pub mod input;
+pub mod io_utils;
+pub mod math;
+pub mod path_finding;
+pub mod progress;
+pub mod registry;
+pub mod solution;
+
 use std::hash::{Hash, Hasher};
 
 #[derive(Debug, Eq, Clone, Copy)]
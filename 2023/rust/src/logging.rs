@@ -0,0 +1,58 @@
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// How much the binary prints besides the answers themselves.
+///
+/// Diagnostics go to stderr, so that they never end up in the answers or the JSON and CSV
+/// records on stdout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum Level {
+    /// Only answers, records and errors
+    Quiet,
+    /// Also summaries such as the run-all table, on stdout
+    #[default]
+    Normal,
+    /// Also a few lines per part about how it was solved
+    Verbose,
+    /// Also a line for every item of the input as it is processed
+    Trace,
+}
+
+static LEVEL: AtomicU8 = AtomicU8::new(Level::Normal as u8);
+
+pub fn set_level(level: Level) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+pub fn enabled(level: Level) -> bool {
+    return LEVEL.load(Ordering::Relaxed) >= level as u8;
+}
+
+/// Prints to stdout unless running with `--quiet`
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)*) => {
+        if $crate::logging::enabled($crate::logging::Level::Normal) {
+            println!($($arg)*);
+        }
+    };
+}
+
+/// Prints to stderr when running with `--verbose` or `--trace`
+#[macro_export]
+macro_rules! verbose {
+    ($($arg:tt)*) => {
+        if $crate::logging::enabled($crate::logging::Level::Verbose) {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+/// Prints to stderr when running with `--trace`
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if $crate::logging::enabled($crate::logging::Level::Trace) {
+            eprintln!($($arg)*);
+        }
+    };
+}
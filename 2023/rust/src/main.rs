@@ -1,51 +1,633 @@
 #![allow(dead_code)]
+#![allow(clippy::needless_return, clippy::ptr_arg, clippy::type_complexity)]
 
-mod day1;
-mod day2;
-mod day3;
-mod day4;
-mod day5;
-mod day6;
-mod day7;
-mod day8;
-mod day9;
-mod day10;
-mod day11;
+#[macro_use]
+extern crate aoc_2023;
 
-mod io_utils;
-mod math;
-mod path_finding;
+mod answers;
+mod bench;
+mod cache;
+mod crash;
+mod cli;
+mod memory;
+mod profile;
+mod report;
+mod runner;
+mod scaffold;
 
-fn main() {
-    //day1::part1::run();
-    // day1::part2::run();
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Instant;
 
-    // day2::part1::run();
-    // day2::part2::run();
+// lets the binary's modules reach the library's as `crate::error` and so on
+use aoc_2023::{cancel, checked, error, input, logging, registry, solution};
 
-    // day3::part1::run();
+use answers::{Answers, Status};
+use bench::Baseline;
+use cache::AnswerCache;
+use cli::{BatchOptions, BenchOptions, Command, RunOptions, RunTarget};
+use crash::Crash;
+use input::InputSource;
+use profile::Profile;
+use rayon::prelude::*;
+use report::Format;
+use report::format_duration;
+use runner::{DayReport, Outcome, RunInput};
+use solution::{Answer, Puzzle};
 
-    // day4::part1::run();
-    // day4::part2::run();
+fn usage() -> String {
+    return cli::usage(&registry::overview());
+}
+
+/// The days and parts a command applies to, or an exit code when the target is invalid
+fn select(target: &RunTarget) -> Result<Vec<(&'static dyn Puzzle, Vec<u8>)>, ExitCode> {
+    return match *target {
+        RunTarget::All => Ok(registry::SOLUTIONS
+            .iter()
+            .map(|puzzle| (*puzzle, puzzle.parts().to_vec()))
+            .collect()),
+        RunTarget::Day { day, part } => {
+            let Some(puzzle) = registry::find(day) else {
+                eprintln!("Day {} has no solution yet\n", day);
+                eprint!("{}", usage());
+                return Err(ExitCode::from(2));
+            };
+
+            match part {
+                None => Ok(vec![(puzzle, puzzle.parts().to_vec())]),
+                Some(part) if puzzle.parts().contains(&part) => Ok(vec![(puzzle, vec![part])]),
+                Some(part) => {
+                    eprintln!("Day {} has no part {}\n", day, part);
+                    eprint!("{}", usage());
+                    Err(ExitCode::from(2))
+                }
+            }
+        }
+    };
+}
+
+/// Solves every selected part, handing each day's answers over in calendar order
+fn solve_all(
+    options: &RunOptions,
+    answers: &Answers,
+    report: impl FnMut(&RunInput, DayReport),
+) -> Result<(), ExitCode> {
+    let selection = select(&options.target)?;
+
+    let mut jobs = Vec::<(&dyn Puzzle, RunInput, Vec<u8>)>::new();
+
+    for (puzzle, parts) in selection {
+        match options.sample {
+            None => jobs.push((puzzle, RunInput::puzzle(&options.input), parts)),
+            Some(number) => {
+                // parts without that sample are skipped, unless none of them has it
+                for part in parts {
+                    if let Some(sample) = puzzle.sample(part, number) {
+                        jobs.push((puzzle, RunInput::sample(puzzle.day(), number, sample), vec![part]));
+                    }
+                }
+            }
+        }
+    }
+
+    if let (Some(number), true) = (options.sample, jobs.is_empty()) {
+        match options.target {
+            RunTarget::All => eprintln!("No day has a sample {}\n", number),
+            RunTarget::Day { day, part: None } => eprintln!("Day {} has no sample {}\n", day, number),
+            RunTarget::Day { day, part: Some(part) } => eprintln!("Day {}, part {} has no sample {}\n", day, part, number),
+        }
+
+        eprint!("{}", usage());
+        return Err(ExitCode::from(2));
+    }
+
+    return solve_jobs(&jobs, options, answers, report);
+}
+
+/// Solves each job, handing the answers over in the order of the jobs. A job that fails to
+/// read its input is reported and skipped rather than ending the run.
+///
+/// Answers in the answer cache are reused unless running with `--no-cache`, and every
+/// answer solved afresh is added to it.
+///
+/// With `--jobs` everything is solved up front on a dedicated rayon pool. Days that use
+/// rayon themselves run on that same pool, so the number of threads never exceeds the
+/// requested number of jobs.
+fn solve_jobs(
+    jobs: &[(&dyn Puzzle, RunInput, Vec<u8>)],
+    options: &RunOptions,
+    answers: &Answers,
+    mut report: impl FnMut(&RunInput, DayReport),
+) -> Result<(), ExitCode> {
+    let pool = match options.jobs {
+        None => None,
+        Some(threads) => match rayon::ThreadPoolBuilder::new().num_threads(threads).build() {
+            Ok(pool) => Some(pool),
+            Err(error) => {
+                eprintln!("Could not start {} worker threads: {}", threads, error);
+                return Err(ExitCode::FAILURE);
+            }
+        },
+    };
+
+    let cache_path = cache::default_cache_path();
+    let mut cache = AnswerCache::load(&cache_path).unwrap_or_else(|error| {
+        eprintln!("Could not load the answer cache from {}, solving every part: {}", cache_path.display(), error);
+        AnswerCache::default()
+    });
+    // a checked build is for finding overflows, which cached answers would hide
+    let lookup = (options.use_cache && !checked::ENABLED).then_some(&cache);
+
+    let solve_job = |(puzzle, input, parts): &(&dyn Puzzle, RunInput, Vec<u8>)| {
+        runner::solve(*puzzle, parts, input, answers, lookup, pool.is_some(), options.time_limit)
+    };
+
+    // serial runs report each day as soon as it is solved
+    let results: Box<dyn Iterator<Item = io::Result<DayReport>>> = match &pool {
+        None => Box::new(jobs.iter().map(solve_job)),
+        Some(pool) => Box::new(pool.install(|| jobs.par_iter().map(solve_job).collect::<Vec<_>>()).into_iter()),
+    };
+
+    let mut all_read = true;
+    let mut solved = Vec::<(u8, u8, String, &str, Answer)>::new();
+
+    for ((puzzle, input, _), result) in jobs.iter().zip(results) {
+        match result {
+            Ok(day_report) => {
+                for part in day_report.parts.iter().filter(|part| !part.cached) {
+                    if let Some(answer) = part.answer() {
+                        solved.push((part.day, part.part, part.checksum.clone(), puzzle.version(), answer.clone()));
+                    }
+                }
+
+                report(input, day_report);
+            }
+            Err(error) => {
+                eprintln!(
+                    "Could not read the input for day {} from {}: {}",
+                    puzzle.day(),
+                    input.source.describe(puzzle.day()),
+                    error
+                );
+                all_read = false;
+            }
+        }
+    }
+
+    if !solved.is_empty() {
+        for (day, part, checksum, version, answer) in &solved {
+            cache.insert(*day, *part, checksum, version, answer);
+        }
+
+        // the answers were still printed, so a cache that cannot be written is not fatal
+        if let Err(error) = cache.save(&cache_path) {
+            eprintln!("Could not save the answer cache to {}: {}", cache_path.display(), error);
+        }
+    }
+
+    return if all_read { Ok(()) } else { Err(ExitCode::FAILURE) };
+}
+
+/// The crate's known answers, with those for the puzzle inputs taken from the profile if
+/// there is one
+fn load_answers(profile: Option<&Profile>) -> Result<Answers, ExitCode> {
+    let path = answers::default_answers_path();
+
+    let answers = Answers::load(&path).map_err(|error| {
+        eprintln!("Could not load the known answers from {}: {}", path.display(), error);
+        ExitCode::FAILURE
+    })?;
+
+    let Some(profile) = profile else {
+        return Ok(answers);
+    };
+
+    if !profile.dir.is_dir() {
+        eprintln!("Profile '{}' does not exist, expected it in {}", profile.name, profile.dir.display());
+        return Err(ExitCode::FAILURE);
+    }
+
+    let path = profile.answers_path();
+
+    // a profile without an answers file yet has every answer unknown
+    let profile_answers = match Answers::load(&path) {
+        Ok(profile_answers) => profile_answers,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Answers::default(),
+        Err(error) => {
+            eprintln!(
+                "Could not load the known answers of profile '{}' from {}: {}",
+                profile.name,
+                path.display(),
+                error
+            );
+            return Err(ExitCode::FAILURE);
+        }
+    };
+
+    return Ok(answers.with_profile(profile_answers));
+}
+
+/// Backtraces are only captured with `RUST_BACKTRACE` set, and go to stderr like the
+/// default panic message would
+fn print_backtrace(crash: &Crash) {
+    if let Some(backtrace) = &crash.backtrace {
+        eprintln!("{}", backtrace);
+    }
+}
+
+/// Prints the results as structured records, which the text format does not have
+fn print_records(format: Format, days: &[DayReport]) {
+    match format {
+        Format::Text => {}
+        Format::Json => print!("{}", report::json_records(days)),
+        Format::Csv => print!("{}", report::csv_records(days)),
+    }
+}
+
+fn run(options: RunOptions) -> ExitCode {
+    let answers = match load_answers(options.profile.as_ref()) {
+        Ok(answers) => answers,
+        Err(code) => return code,
+    };
+
+    let text = options.format == Format::Text;
+    let summarise = text && options.target == RunTarget::All;
+
+    let mut any_failed = false;
+    let mut days = Vec::<DayReport>::new();
+
+    let start = Instant::now();
+    let result = solve_all(&options, &answers, |_, day| {
+        for report in &day.parts {
+            let status = report.status();
+            any_failed |= matches!(status, Status::Fail | Status::TimedOut | Status::Crashed | Status::Error);
+
+            if !text {
+                continue;
+            }
+
+            match &report.outcome {
+                Outcome::TimedOut => {
+                    println!("{}: timed out after {}", report.label(), format_duration(report.solve_time));
+                }
+                Outcome::Crashed(crash) => {
+                    println!("{}: crashed: {}", report.label(), crash);
+                    print_backtrace(crash);
+                }
+                Outcome::Failed(error) => println!("{}: error: {}", report.label(), error.in_input()),
+                Outcome::Solved(answer) if status == Status::Fail => {
+                    println!(
+                        "{}: {} (expected {})",
+                        report.label(),
+                        answer,
+                        report.expected_answer().map(|expected| expected.to_string()).unwrap_or_default()
+                    );
+                }
+                Outcome::Solved(answer) => println!("{}: {}", report.label(), answer),
+            }
+
+            // run-all lists these in its summary table instead
+            if report.cached && !summarise {
+                info!("  from the answer cache");
+            }
+
+            if let Some(memory) = report.memory.filter(|_| !summarise) {
+                info!(
+                    "  allocated {}, peak {}",
+                    report::format_bytes(memory.allocated),
+                    report::format_bytes(memory.peak)
+                );
+            }
+        }
+
+        if summarise || !text {
+            days.push(day);
+        }
+    });
+    let wall_time = start.elapsed();
+
+    print_records(options.format, &days);
+
+    if summarise {
+        info!("\n{}", report::summary_table(&days, wall_time).trim_end());
+    }
+
+    return match result {
+        Err(code) => code,
+        Ok(()) if any_failed => ExitCode::FAILURE,
+        Ok(()) => ExitCode::SUCCESS,
+    };
+}
+
+fn verify(options: RunOptions) -> ExitCode {
+    let answers = match load_answers(options.profile.as_ref()) {
+        Ok(answers) => answers,
+        Err(code) => return code,
+    };
+
+    let text = options.format == Format::Text;
 
-    // day5::part1::run();
-    // day5::part2::run();
+    let (mut passed, mut failed, mut unknown, mut different_input) = (0, 0, 0, 0);
+    let (mut timed_out, mut crashed, mut errors) = (0, 0, 0);
+    let mut days = Vec::<DayReport>::new();
 
-    // day6::part1::run();
-    // day6::part2::run();
+    let result = solve_all(&options, &answers, |_, day| {
+        for report in &day.parts {
+            let status = report.status();
 
-    // day7::part1::run();
-    // day7::part2::run();
+            match status {
+                Status::Pass => passed += 1,
+                Status::Fail => failed += 1,
+                Status::Unknown => unknown += 1,
+                Status::DifferentInput => different_input += 1,
+                Status::TimedOut => timed_out += 1,
+                Status::Crashed => crashed += 1,
+                Status::Error => errors += 1,
+            }
 
-    //day8::part1::run();
-    // day8::part2::run();
+            if !text {
+                continue;
+            }
+
+            let cached = if report.cached { ", cached" } else { "" };
+
+            match (&report.outcome, &report.expected) {
+                (Outcome::TimedOut, _) => {
+                    println!("{}: {} (after {})", report.label(), status, format_duration(report.solve_time))
+                }
+                (Outcome::Crashed(crash), _) => {
+                    println!("{}: {} ({})", report.label(), status, crash);
+                    print_backtrace(crash);
+                }
+                (Outcome::Failed(error), _) => println!("{}: {} ({})", report.label(), status, error.in_input()),
+                (Outcome::Solved(answer), Some(expected)) if status == Status::Fail => {
+                    println!("{}: {} ({}, expected {}{})", report.label(), status, answer, expected.answer, cached)
+                }
+                (Outcome::Solved(answer), Some(expected)) if status == Status::DifferentInput => {
+                    println!(
+                        "{}: {} ({}, the known answer {} is for input {} rather than {}{})",
+                        report.label(),
+                        status,
+                        answer,
+                        expected.answer,
+                        expected.checksum.as_deref().unwrap_or_default(),
+                        report.checksum,
+                        cached
+                    )
+                }
+                (Outcome::Solved(answer), _) => println!("{}: {} ({}{})", report.label(), status, answer, cached),
+            }
+        }
+
+        if !text {
+            days.push(day);
+        }
+    });
+
+    print_records(options.format, &days);
+
+    // a selection that could not be run has already been reported, and has nothing to count
+    if text && result.is_ok() {
+        info!(
+            "\n{} passed, {} failed, {} unknown, {} for a different input, {} timed out, {} crashed, {} errors",
+            passed, failed, unknown, different_input, timed_out, crashed, errors
+        );
+    }
+
+    return match result {
+        Err(code) => code,
+        Ok(()) if failed + timed_out + crashed + errors > 0 => ExitCode::FAILURE,
+        Ok(()) => ExitCode::SUCCESS,
+    };
+}
+
+fn bench(options: BenchOptions) -> ExitCode {
+    let selection = match select(&RunTarget::Day { day: options.day, part: options.part }) {
+        Ok(selection) => selection,
+        Err(code) => return code,
+    };
+    let (puzzle, parts) = &selection[0];
+
+    let mut baseline = match Baseline::load(&options.baseline) {
+        Ok(baseline) => baseline,
+        Err(error) => {
+            eprintln!("Could not load the benchmark baseline from {}: {}", options.baseline.display(), error);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    info!(
+        "Day {}: {} warm-up run(s), {} timed run(s)\n",
+        puzzle.day(),
+        options.settings.warmup,
+        options.settings.iterations
+    );
+
+    let raw_input = match options.input.read(puzzle.day()) {
+        Ok(raw_input) => raw_input,
+        Err(error) => {
+            eprintln!(
+                "Could not read the input for day {} from {}: {}",
+                puzzle.day(),
+                options.input.describe(puzzle.day()),
+                error
+            );
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let measurements = match bench::measure(*puzzle, parts, &raw_input, &options.settings) {
+        Ok(measurements) => measurements,
+        Err(error) => {
+            eprintln!("Could not benchmark {}: {}", options.input.describe(puzzle.day()), error);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let (table, regressed) = bench::compare(puzzle.day(), &measurements, &baseline, options.settings.threshold);
+    print!("{}", table);
+
+    // steps without a baseline get one from this run, the rest only when asked to
+    let to_save = measurements
+        .iter()
+        .filter(|measurement| options.save_baseline || baseline.get(puzzle.day(), measurement.step).is_none())
+        .collect::<Vec<_>>();
+
+    if !to_save.is_empty() {
+        for measurement in to_save {
+            baseline.set(puzzle.day(), measurement.step, measurement.stats.median);
+        }
+
+        if let Err(error) = baseline.save(&options.baseline) {
+            eprintln!("Could not save the benchmark baseline to {}: {}", options.baseline.display(), error);
+            return ExitCode::FAILURE;
+        }
+
+        info!("\nSaved the medians as the baseline in {}", options.baseline.display());
+    }
+
+    if regressed {
+        println!("\nSlower than the baseline by more than {}%", options.settings.threshold);
+        return ExitCode::FAILURE;
+    }
+
+    return ExitCode::SUCCESS;
+}
+
+/// The files in `dir` in name order, leaving out hidden ones such as editor swap files
+fn input_files(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut files = Vec::<PathBuf>::new();
+
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let hidden = entry.file_name().to_string_lossy().starts_with('.');
+
+        if !hidden && entry.file_type()?.is_file() {
+            files.push(entry.path());
+        }
+    }
+
+    files.sort();
+
+    return Ok(files);
+}
+
+fn batch(options: BatchOptions) -> ExitCode {
+    let answers = match load_answers(options.run.profile.as_ref()) {
+        Ok(answers) => answers,
+        Err(code) => return code,
+    };
+
+    let selection = match select(&options.run.target) {
+        Ok(selection) => selection,
+        Err(code) => return code,
+    };
+    let (puzzle, parts) = &selection[0];
+
+    let files = match input_files(&options.dir) {
+        Ok(files) if files.is_empty() => {
+            eprintln!("No input files in {}", options.dir.display());
+            return ExitCode::FAILURE;
+        }
+        Ok(files) => files,
+        Err(error) => {
+            eprintln!("Could not list the inputs in {}: {}", options.dir.display(), error);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    // the inputs are matched to known answers by checksum, whatever they are called
+    let jobs = files
+        .into_iter()
+        .map(|path| (*puzzle, RunInput { source: InputSource::File(path), name: None, sample: None }, parts.clone()))
+        .collect::<Vec<_>>();
+
+    let mut any_failed = false;
+    let mut inputs = Vec::<(String, DayReport)>::new();
+
+    let result = solve_jobs(&jobs, &options.run, &answers, |input, day| {
+        any_failed |= day
+            .parts
+            .iter()
+            .any(|part| matches!(part.status(), Status::Fail | Status::TimedOut | Status::Crashed | Status::Error));
+
+        let name = input
+            .source
+            .path(day.day)
+            .and_then(|path| Some(path.file_name()?.to_string_lossy().into_owned()))
+            .unwrap_or_default();
+
+        inputs.push((name, day));
+    });
+
+    if !inputs.is_empty() {
+        print!("{}", report::batch_table(&inputs));
+    }
+
+    return match result {
+        Err(code) => code,
+        Ok(()) if any_failed => ExitCode::FAILURE,
+        Ok(()) => ExitCode::SUCCESS,
+    };
+}
+
+fn lint(day: u8, input: InputSource) -> ExitCode {
+    let Some(puzzle) = registry::find(day) else {
+        eprintln!("Day {} has no solution yet\n", day);
+        eprint!("{}", usage());
+        return ExitCode::from(2);
+    };
+
+    let raw_input = match input.read(day) {
+        Ok(raw_input) => raw_input,
+        Err(error) => {
+            eprintln!("Could not read the input for day {} from {}: {}", day, input.describe(day), error);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut errors = puzzle.lint(&raw_input);
+    errors.sort_by_key(|error| (error.line, error.column));
+
+    for error in &errors {
+        println!("{}: {}", input.describe(day), error.in_input());
+    }
+
+    if errors.is_empty() {
+        info!("{}: no problems found for day {}", input.describe(day), day);
+        return ExitCode::SUCCESS;
+    }
+
+    info!("\n{} problem(s) found", errors.len());
+
+    return ExitCode::FAILURE;
+}
+
+fn new_day(day: u8) -> ExitCode {
+    if registry::find(day).is_some() {
+        eprintln!("Day {} already has a solution", day);
+        return ExitCode::FAILURE;
+    }
+
+    return match scaffold::new_day(&scaffold::crate_dir(), day) {
+        Ok(paths) => {
+            println!("Added day {}:", day);
+            paths.iter().for_each(|path| println!("  {}", path.display()));
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("Could not add day {}: {}", day, error);
+            ExitCode::FAILURE
+        }
+    };
+}
 
-    // day9::part1::run();
-    // day9::part2::run();
+fn main() -> ExitCode {
+    let args = env::args().skip(1).collect::<Vec<_>>();
 
-    // day10::part1::run();
-    // day10::part2::run();
+    let command = cli::parse_args(&args).map(|(command, level)| {
+        logging::set_level(level);
+        command
+    });
 
-    // day11::part1::run();
-    day11::part2::run();
+    return match command {
+        Ok(Command::Run(options)) => run(options),
+        Ok(Command::Verify(options)) => verify(options),
+        Ok(Command::Bench(options)) => bench(options),
+        Ok(Command::Batch(options)) => batch(options),
+        Ok(Command::New { day }) => new_day(day),
+        Ok(Command::Lint { day, input }) => lint(day, input),
+        Ok(Command::Help) => {
+            print!("{}", usage());
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("error: {}\n", error);
+            eprint!("{}", usage());
+            ExitCode::from(2)
+        }
+    };
 }
@@ -1,3 +1,6 @@
+use crate::checked;
+use crate::error::Result;
+
 /// Greatest Common Divisor
 pub fn gcd(a: u64, b: u64) -> u64 {
     if b == 0 {
@@ -8,19 +11,20 @@ pub fn gcd(a: u64, b: u64) -> u64 {
 }
 
 /// Least Common Multiplier
-pub fn lcm(a: u64, b: u64) -> u64 {
+pub fn lcm(a: u64, b: u64) -> Result<u64> {
     if a == 0 || b == 0 {
-        0
+        Ok(0)
     } else {
-        (a * b) / gcd(a, b)
+        // dividing first keeps the intermediate no larger than the lcm itself
+        checked::mul(a / gcd(a, b), b, "lcm")
     }
 }
 
-pub fn calculate_lcm(numbers: &[u64]) -> u64 {
+pub fn calculate_lcm(numbers: &[u64]) -> Result<u64> {
     if numbers.is_empty() {
-        0
+        Ok(0)
     } else {
-        numbers.iter().cloned().fold(1, lcm)
+        numbers.iter().cloned().try_fold(1, lcm)
     }
 }
 
@@ -0,0 +1,181 @@
+/// Heap usage of a solver while it ran
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MemoryStats {
+    /// Bytes allocated in total, including memory that was freed again
+    pub allocated: usize,
+    /// Highest heap usage above what was in use when the solver started
+    pub peak: usize,
+}
+
+#[cfg(feature = "memory-stats")]
+mod counting {
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+
+    pub static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+    pub static CURRENT: AtomicUsize = AtomicUsize::new(0);
+
+    /// How many measurements can run at the same time, one per bit of [`ACTIVE`]
+    const SLOTS: usize = 64;
+
+    /// The slots of the measurements in progress
+    static ACTIVE: AtomicU64 = AtomicU64::new(0);
+    /// The highest heap usage seen by each measurement in progress, so that starting one
+    /// does not reset the peak of those already running
+    static PEAKS: [AtomicUsize; SLOTS] = [const { AtomicUsize::new(0) }; SLOTS];
+
+    /// Takes a free slot for a measurement starting at `start_current` bytes in use, or
+    /// `None` when every slot is taken
+    pub fn start_measurement(start_current: usize) -> Option<usize> {
+        let mut active = ACTIVE.load(Ordering::Relaxed);
+
+        loop {
+            let slot = (!active).trailing_zeros() as usize;
+
+            if slot >= SLOTS {
+                return None;
+            }
+
+            PEAKS[slot].store(start_current, Ordering::Relaxed);
+
+            match ACTIVE.compare_exchange_weak(active, active | 1 << slot, Ordering::Relaxed, Ordering::Relaxed) {
+                Ok(_) => return Some(slot),
+                Err(actual) => active = actual,
+            }
+        }
+    }
+
+    /// Frees the slot, returning the peak it recorded
+    pub fn end_measurement(slot: usize) -> usize {
+        let peak = PEAKS[slot].load(Ordering::Relaxed);
+        ACTIVE.fetch_and(!(1 << slot), Ordering::Relaxed);
+
+        return peak;
+    }
+
+    /// The system allocator, keeping count of the bytes that go through it
+    pub struct CountingAllocator;
+
+    fn record_alloc(size: usize) {
+        ALLOCATED.fetch_add(size, Ordering::Relaxed);
+
+        let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+        let mut active = ACTIVE.load(Ordering::Relaxed);
+
+        while active != 0 {
+            PEAKS[active.trailing_zeros() as usize].fetch_max(current, Ordering::Relaxed);
+            active &= active - 1;
+        }
+    }
+
+    fn record_dealloc(size: usize) {
+        CURRENT.fetch_sub(size, Ordering::Relaxed);
+    }
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc(layout);
+
+            if !ptr.is_null() {
+                record_alloc(layout.size());
+            }
+
+            return ptr;
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc_zeroed(layout);
+
+            if !ptr.is_null() {
+                record_alloc(layout.size());
+            }
+
+            return ptr;
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout);
+            record_dealloc(layout.size());
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new_ptr = System.realloc(ptr, layout, new_size);
+
+            // growing in place or not, only the extra bytes count as newly allocated
+            if !new_ptr.is_null() {
+                if new_size > layout.size() {
+                    record_alloc(new_size - layout.size());
+                } else {
+                    record_dealloc(layout.size() - new_size);
+                }
+            }
+
+            return new_ptr;
+        }
+    }
+
+    #[global_allocator]
+    static GLOBAL: CountingAllocator = CountingAllocator;
+}
+
+/// Runs `solve` and reports its heap usage, when built with the `memory-stats` feature.
+///
+/// The counters are process wide, so parts solved at the same time with `--jobs` are
+/// counted together. Each measurement keeps its own peak though, so one starting does not
+/// hide the peak of another still running.
+#[cfg(feature = "memory-stats")]
+pub fn measure<T>(solve: impl FnOnce() -> T) -> (T, Option<MemoryStats>) {
+    use std::sync::atomic::Ordering;
+
+    use counting::{end_measurement, start_measurement, ALLOCATED, CURRENT};
+
+    let start_allocated = ALLOCATED.load(Ordering::Relaxed);
+    let start_current = CURRENT.load(Ordering::Relaxed);
+
+    let Some(slot) = start_measurement(start_current) else {
+        // more measurements at once than there are slots for, so this one goes unmeasured
+        return (solve(), None);
+    };
+
+    let result = solve();
+    let peak = end_measurement(slot);
+
+    let stats = MemoryStats {
+        allocated: ALLOCATED.load(Ordering::Relaxed) - start_allocated,
+        peak: peak.saturating_sub(start_current),
+    };
+
+    return (result, Some(stats));
+}
+
+/// Runs `solve`. Heap usage is only counted with the `memory-stats` feature enabled.
+#[cfg(not(feature = "memory-stats"))]
+pub fn measure<T>(solve: impl FnOnce() -> T) -> (T, Option<MemoryStats>) {
+    return (solve(), None);
+}
+
+#[cfg(all(test, feature = "memory-stats"))]
+mod tests {
+    use std::hint::black_box;
+
+    use super::measure;
+
+    const MIB: usize = 1 << 20;
+
+    #[test]
+    fn overlapping_measurements_keep_their_own_peak() {
+        let ((_, inner), outer) = measure(|| {
+            drop(black_box(vec![0u8; 4 * MIB]));
+
+            // starts after the outer measurement's peak, which it must not reset
+            return measure(|| drop(black_box(vec![0u8; MIB])));
+        });
+
+        let (inner, outer) = (inner.unwrap(), outer.unwrap());
+
+        assert!(outer.peak >= 4 * MIB, "outer peak {}", outer.peak);
+        assert!(outer.allocated >= 5 * MIB, "outer allocated {}", outer.allocated);
+        assert!(inner.peak >= MIB && inner.peak < 4 * MIB, "inner peak {}", inner.peak);
+        assert!(inner.allocated >= MIB, "inner allocated {}", inner.allocated);
+    }
+}
@@ -1,6 +1,6 @@
 use std::collections::HashSet;
 
-use aoc_2023::Point;
+use crate::Point;
 
 #[derive(Debug, Eq, Hash, PartialEq, Clone, Copy)]
 pub struct StartGoal {
@@ -0,0 +1,76 @@
+use std::path::PathBuf;
+
+use crate::input::{config_dir, YEAR};
+
+/// Environment variable naming the profile to use when `--profile` is not given
+pub const PROFILE_ENV: &str = "AOC_PROFILE";
+
+/// Somebody's puzzle inputs and the answers known for them, so that teammates with
+/// different inputs can each run and verify their own.
+///
+/// A profile lives in `~/.config/aoc/profiles/<name>/`, with the inputs in `inputs/2023/`
+/// laid out like any inputs directory and the answers in `answers/2023.txt`, in the same
+/// format as the crate's `answers.txt`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Profile {
+    pub name: String,
+    pub dir: PathBuf,
+}
+
+impl Profile {
+    pub fn new(name: &str) -> Result<Profile, String> {
+        let valid = !name.is_empty()
+            && name
+                .chars()
+                .all(|ch| ch.is_ascii_alphanumeric() || ch == '-' || ch == '_' || ch == '.')
+            && !name.starts_with('.');
+
+        if !valid {
+            return Err(format!(
+                "invalid profile name '{}', expected letters, digits, '-', '_' or '.'",
+                name
+            ));
+        }
+
+        let profiles_dir = config_dir()
+            .ok_or_else(|| String::from("cannot locate profiles without HOME or XDG_CONFIG_HOME"))?
+            .join("aoc")
+            .join("profiles");
+
+        return Ok(Profile { name: name.to_owned(), dir: profiles_dir.join(name) });
+    }
+
+    pub fn inputs_dir(&self) -> PathBuf {
+        return self.dir.join("inputs").join(YEAR.to_string());
+    }
+
+    pub fn answers_path(&self) -> PathBuf {
+        return self.dir.join("answers").join(format!("{}.txt", YEAR));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::Profile;
+
+    #[test]
+    fn names_that_are_not_a_single_directory_are_rejected() {
+        for name in ["", ".", "..", "../alice", "alice/work", "alice\\work", "/alice", ".hidden", "al ice"] {
+            let error = Profile::new(name).unwrap_err();
+
+            assert!(error.starts_with("invalid profile name"), "{:?}: {}", name, error);
+        }
+    }
+
+    #[test]
+    fn plain_names_are_directories_under_the_profiles() {
+        for name in ["alice", "bob-2023", "work_laptop", "alice.old"] {
+            let profile = Profile::new(name).unwrap();
+
+            assert_eq!(profile.name, name);
+            assert!(profile.dir.ends_with(Path::new("aoc").join("profiles").join(name)), "{:?}", profile);
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use std::io::{self, IsTerminal, Write};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::logging::{self, Level};
+
+/// How often the progress line is redrawn at most
+const RENDER_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Progress of a long running solver, shared by reference between rayon workers.
+///
+/// Renders a single updating line with the rate and ETA on stderr when it is a terminal,
+/// and does nothing otherwise or with `--quiet`. The line is cleared when the handle is
+/// dropped.
+pub struct Progress {
+    label: String,
+    total: usize,
+    done: AtomicUsize,
+    start: Instant,
+    /// When the line was last drawn, or `None` when not rendering at all
+    last_render: Option<Mutex<Instant>>,
+}
+
+fn format_eta(seconds: f64) -> String {
+    let seconds = seconds.round() as u64;
+
+    return if seconds >= 3600 {
+        format!("{}h {:02}m", seconds / 3600, seconds % 3600 / 60)
+    } else if seconds >= 60 {
+        format!("{}m {:02}s", seconds / 60, seconds % 60)
+    } else {
+        format!("{}s", seconds)
+    };
+}
+
+impl Progress {
+    pub fn new(label: &str, total: usize) -> Progress {
+        let enabled = io::stderr().is_terminal() && logging::enabled(Level::Normal);
+        let start = Instant::now();
+
+        return Progress {
+            label: label.to_owned(),
+            total,
+            done: AtomicUsize::new(0),
+            start,
+            last_render: enabled.then(|| Mutex::new(start.checked_sub(RENDER_INTERVAL).unwrap_or(start))),
+        };
+    }
+
+    /// Records `count` more items as done. Safe to call from many threads at once.
+    pub fn tick(&self, count: usize) {
+        let done = self.done.fetch_add(count, Ordering::Relaxed) + count;
+
+        let Some(last_render) = &self.last_render else {
+            return;
+        };
+
+        // a worker that finds another one drawing just carries on
+        let Ok(mut last_render) = last_render.try_lock() else {
+            return;
+        };
+
+        if last_render.elapsed() < RENDER_INTERVAL && done < self.total {
+            return;
+        }
+
+        *last_render = Instant::now();
+        self.render(done);
+    }
+
+    fn render(&self, done: usize) {
+        let elapsed = self.start.elapsed().as_secs_f64();
+        let rate = if elapsed > 0.0 { done as f64 / elapsed } else { 0.0 };
+        let remaining = self.total.saturating_sub(done);
+
+        let eta = if rate > 0.0 { format_eta(remaining as f64 / rate) } else { String::from("?") };
+        let percent = if self.total > 0 { done as f64 * 100.0 / self.total as f64 } else { 100.0 };
+
+        let mut stderr = io::stderr().lock();
+        let _ = write!(
+            stderr,
+            "\r\x1b[2K{}: {}/{} ({:.1}%), {:.0}/s, ETA {}",
+            self.label, done, self.total, percent, rate, eta
+        );
+        let _ = stderr.flush();
+    }
+}
+
+impl Drop for Progress {
+    fn drop(&mut self) {
+        if self.last_render.is_some() {
+            let mut stderr = io::stderr().lock();
+            let _ = write!(stderr, "\r\x1b[2K");
+            let _ = stderr.flush();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::format_eta;
+
+    #[test]
+    fn eta_under_a_minute_is_in_seconds() {
+        assert_eq!(format_eta(0.0), "0s");
+        assert_eq!(format_eta(59.4), "59s");
+    }
+
+    #[test]
+    fn eta_under_an_hour_is_in_minutes_and_seconds() {
+        assert_eq!(format_eta(59.6), "1m 00s");
+        assert_eq!(format_eta(125.0), "2m 05s");
+    }
+
+    #[test]
+    fn eta_of_an_hour_or_more_is_in_hours_and_minutes() {
+        assert_eq!(format_eta(3600.0), "1h 00m");
+        assert_eq!(format_eta(2.0 * 3600.0 + 7.0 * 60.0 + 59.0), "2h 07m");
+    }
+}
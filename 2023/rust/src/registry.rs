@@ -0,0 +1,39 @@
+use crate::day1::Day1;
+use crate::day2::Day2;
+use crate::day3::Day3;
+use crate::day4::Day4;
+use crate::day5::Day5;
+use crate::day6::Day6;
+use crate::day7::Day7;
+use crate::day8::Day8;
+use crate::day9::Day9;
+use crate::day10::Day10;
+use crate::day11::Day11;
+use crate::solution::Puzzle;
+
+/// Every solved day, in calendar order
+pub static SOLUTIONS: &[&dyn Puzzle] = &[
+    &Day1,
+    &Day2,
+    &Day3,
+    &Day4,
+    &Day5,
+    &Day6,
+    &Day7,
+    &Day8,
+    &Day9,
+    &Day10,
+    &Day11,
+];
+
+pub fn find(day: u8) -> Option<&'static dyn Puzzle> {
+    return SOLUTIONS.iter().find(|puzzle| puzzle.day() == day).copied();
+}
+
+/// Each registered day together with the parts it can solve
+pub fn overview() -> Vec<(u8, Vec<u8>)> {
+    return SOLUTIONS
+        .iter()
+        .map(|puzzle| (puzzle.day(), puzzle.parts().to_vec()))
+        .collect();
+}
@@ -0,0 +1,424 @@
+use std::time::Duration;
+
+use crate::answers::Status;
+use crate::runner::{DayReport, Outcome};
+use crate::solution::Answer;
+
+/// How the results of a run are printed
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Format {
+    /// One line per part as it is solved, for reading in a terminal
+    #[default]
+    Text,
+    /// A JSON array with one record per part, printed once every part is solved
+    Json,
+    /// A header line followed by one record per part, printed once every part is solved
+    Csv,
+}
+
+impl Format {
+    pub fn from_name(name: &str) -> Option<Format> {
+        return match name {
+            "text" => Some(Format::Text),
+            "json" => Some(Format::Json),
+            "csv" => Some(Format::Csv),
+            _ => None,
+        };
+    }
+}
+
+/// Parts that take at least this long, parse time included, are flagged in the summary
+pub const SLOW_THRESHOLD: Duration = Duration::from_secs(1);
+
+pub fn format_duration(duration: Duration) -> String {
+    let seconds = duration.as_secs_f64();
+
+    return if seconds >= 1.0 {
+        format!("{:.2} s", seconds)
+    } else if seconds >= 1e-3 {
+        format!("{:.2} ms", seconds * 1e3)
+    } else {
+        format!("{:.2} µs", seconds * 1e6)
+    };
+}
+
+pub fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    return format!("{:.1} {}", value, UNITS[unit]);
+}
+
+/// Lines the rows up under the header, left aligning the first `left_aligned` columns. The
+/// last column is a note, and is left out of the rule under the header.
+fn render_table(header: &[&str], rows: &[Vec<String>], left_aligned: usize) -> String {
+    let mut widths = header.iter().map(|title| title.chars().count()).collect::<Vec<_>>();
+
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let format_row = |cells: &[String]| {
+        let line = cells
+            .iter()
+            .zip(&widths)
+            .enumerate()
+            .map(|(column, (cell, width))| {
+                // timings and sizes line up on the decimal point when right aligned
+                if column < left_aligned {
+                    format!("{:<width$}", cell, width = width)
+                } else {
+                    format!("{:>width$}", cell, width = width)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("  ");
+
+        format!("{}\n", line.trim_end())
+    };
+
+    let header = header.iter().map(|title| title.to_string()).collect::<Vec<_>>();
+    let rule = widths.iter().map(|width| "-".repeat(*width)).collect::<Vec<_>>();
+
+    let mut table = format_row(&header);
+    table.push_str(&format_row(&rule[..rule.len() - 1]));
+
+    for row in rows {
+        table.push_str(&format_row(row));
+    }
+
+    return table;
+}
+
+/// Renders every solved part as one table row. A day's parse time is listed on its first
+/// part only, since the parts share the parsed input. The memory columns only show up
+/// when the binary was built to count allocations.
+///
+/// The total is the `wall_time` the whole run took, which with `--jobs` is less than the
+/// time spent in the parts, so that is listed next to it.
+pub fn summary_table(days: &[DayReport], wall_time: Duration) -> String {
+    let with_memory = days.iter().flat_map(|day| &day.parts).any(|part| part.memory.is_some());
+
+    let mut header = vec!["Day", "Part", "Answer", "Status", "Parse", "Solve"];
+    if with_memory {
+        header.extend(["Allocated", "Peak"]);
+    }
+    header.push("");
+
+    let mut rows = Vec::<Vec<String>>::new();
+    let mut part_time = Duration::ZERO;
+    let mut slow = 0;
+
+    for day in days {
+        part_time += day.parse_time;
+
+        for (ix, part) in day.parts.iter().enumerate() {
+            let parse_time = if ix == 0 { day.parse_time } else { Duration::ZERO };
+            let is_slow = parse_time + part.solve_time >= SLOW_THRESHOLD;
+
+            part_time += part.solve_time;
+            slow += is_slow as usize;
+
+            let mut row = vec![
+                day.day.to_string(),
+                match part.sample {
+                    Some(number) => format!("{} (sample {})", part.part, number),
+                    None => part.part.to_string(),
+                },
+                part.answer().map_or(String::from("-"), |answer| answer.to_string()),
+                part.status().to_string(),
+                if ix == 0 { format_duration(day.parse_time) } else { String::new() },
+                if part.cached { String::from("cached") } else { format_duration(part.solve_time) },
+            ];
+
+            if with_memory {
+                match part.memory {
+                    Some(memory) => row.extend([format_bytes(memory.allocated), format_bytes(memory.peak)]),
+                    None => row.extend([String::from("-"), String::from("-")]),
+                }
+            }
+
+            row.push(String::from(if is_slow { "<- slow" } else { "" }));
+            rows.push(row);
+        }
+    }
+
+    let mut table = render_table(&header, &rows, 4);
+    table.push_str(&format!(
+        "\nTotal time: {} ({} parsing and solving)\n",
+        format_duration(wall_time),
+        format_duration(part_time)
+    ));
+
+    if slow > 0 {
+        table.push_str(&format!(
+            "{} part(s) took {} or more\n",
+            slow,
+            format_duration(SLOW_THRESHOLD)
+        ));
+    }
+
+    return table;
+}
+
+/// Renders one row per input and part for `batch`, followed by what went wrong with the
+/// parts that could not be solved
+pub fn batch_table(inputs: &[(String, DayReport)]) -> String {
+    let header = ["Input", "Part", "Answer", "Status", "Parse", "Solve", ""];
+
+    let mut rows = Vec::<Vec<String>>::new();
+    let mut problems = Vec::<String>::new();
+
+    for (name, day) in inputs {
+        for (ix, part) in day.parts.iter().enumerate() {
+            let status = part.status();
+
+            rows.push(vec![
+                name.clone(),
+                part.part.to_string(),
+                part.answer().map_or(String::from("-"), |answer| answer.to_string()),
+                status.to_string(),
+                if ix == 0 { format_duration(day.parse_time) } else { String::new() },
+                if part.cached { String::from("cached") } else { format_duration(part.solve_time) },
+                match part.expected_answer() {
+                    Some(expected) if status == Status::Fail => format!("expected {}", expected),
+                    _ => String::new(),
+                },
+            ]);
+
+            let problem = match &part.outcome {
+                Outcome::Solved(_) => continue,
+                Outcome::TimedOut => format!("timed out after {}", format_duration(part.solve_time)),
+                Outcome::Crashed(crash) => format!("crashed: {}", crash),
+                Outcome::Failed(error) => format!("error: {}", error.in_input()),
+            };
+
+            problems.push(format!("{}, part {}: {}\n", name, part.part, problem));
+        }
+    }
+
+    let mut table = render_table(&header, &rows, 4);
+
+    if !problems.is_empty() {
+        table.push('\n');
+        table.push_str(&problems.concat());
+    }
+
+    return table;
+}
+
+const RECORD_FIELDS: [&str; 12] = [
+    "day",
+    "part",
+    "sample",
+    "answer",
+    "expected",
+    "status",
+    "parse_ns",
+    "solve_ns",
+    "allocated_bytes",
+    "peak_bytes",
+    "error",
+    "cached",
+];
+
+/// A field of a structured record, kept apart from its encoding so JSON and CSV agree
+enum Field {
+    Number(u128),
+    Text(String),
+    Flag(bool),
+    Answer(Answer),
+    Missing,
+}
+
+/// The fields of every record, in [`RECORD_FIELDS`] order. Unlike the summary table, each
+/// record repeats the parse time of the input its part was solved from.
+fn records(days: &[DayReport]) -> Vec<[Field; 12]> {
+    return days
+        .iter()
+        .flat_map(|day| {
+            day.parts.iter().map(|part| {
+                [
+                    Field::Number(day.day as u128),
+                    Field::Number(part.part as u128),
+                    part.sample.map_or(Field::Missing, |number| Field::Number(number as u128)),
+                    part.answer().map_or(Field::Missing, |answer| Field::Answer(answer.clone())),
+                    part.expected_answer().map_or(Field::Missing, |expected| Field::Answer(expected.clone())),
+                    Field::Text(part.status().to_string()),
+                    Field::Number(day.parse_time.as_nanos()),
+                    Field::Number(part.solve_time.as_nanos()),
+                    part.memory.map_or(Field::Missing, |memory| Field::Number(memory.allocated as u128)),
+                    part.memory.map_or(Field::Missing, |memory| Field::Number(memory.peak as u128)),
+                    match &part.outcome {
+                        Outcome::Crashed(crash) => Field::Text(crash.to_string()),
+                        Outcome::Failed(error) => Field::Text(error.to_string()),
+                        _ => Field::Missing,
+                    },
+                    Field::Flag(part.cached),
+                ]
+            })
+        })
+        .collect();
+}
+
+fn json_string(text: &str) -> String {
+    let mut quoted = String::from("\"");
+
+    for ch in text.chars() {
+        match ch {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            ch if ch.is_control() => quoted.push_str(&format!("\\u{:04x}", ch as u32)),
+            ch => quoted.push(ch),
+        }
+    }
+
+    quoted.push('"');
+    return quoted;
+}
+
+/// Integers beyond this are rounded by JSON parsers that read every number into a double
+const JSON_MAX_SAFE_INTEGER: u64 = (1 << 53) - 1;
+
+/// Integer answers are numbers, except for those beyond [`JSON_MAX_SAFE_INTEGER`], which
+/// are strings like text answers so that they survive JSON parsers intact
+pub fn json_records(days: &[DayReport]) -> String {
+    let lines = records(days)
+        .iter()
+        .map(|record| {
+            let fields = RECORD_FIELDS
+                .iter()
+                .zip(record)
+                .map(|(name, field)| {
+                    let value = match field {
+                        Field::Number(number) => number.to_string(),
+                        Field::Text(text) => json_string(text),
+                        Field::Answer(Answer::Int(value)) if value.unsigned_abs() <= JSON_MAX_SAFE_INTEGER => {
+                            value.to_string()
+                        }
+                        Field::Answer(answer) => json_string(&answer.to_string()),
+                        Field::Flag(flag) => flag.to_string(),
+                        Field::Missing => String::from("null"),
+                    };
+
+                    format!("{}: {}", json_string(name), value)
+                })
+                .collect::<Vec<_>>();
+
+            format!("  {{{}}}", fields.join(", "))
+        })
+        .collect::<Vec<_>>();
+
+    return if lines.is_empty() {
+        String::from("[]\n")
+    } else {
+        format!("[\n{}\n]\n", lines.join(",\n"))
+    };
+}
+
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        return format!("\"{}\"", text.replace('"', "\"\""));
+    }
+
+    return text.to_owned();
+}
+
+/// Missing values are left empty
+pub fn csv_records(days: &[DayReport]) -> String {
+    let mut csv = format!("{}\n", RECORD_FIELDS.join(","));
+
+    for record in records(days) {
+        let fields = record
+            .iter()
+            .map(|field| match field {
+                Field::Number(number) => number.to_string(),
+                Field::Text(text) => csv_field(text),
+                Field::Answer(answer) => csv_field(&answer.to_string()),
+                Field::Flag(flag) => flag.to_string(),
+                Field::Missing => String::new(),
+            })
+            .collect::<Vec<_>>();
+
+        csv.push_str(&format!("{}\n", fields.join(",")));
+    }
+
+    return csv;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{csv_field, json_records, json_string};
+    use crate::runner::{DayReport, Outcome, PartReport};
+    use crate::solution::Answer;
+
+    fn report_with_answer(answer: Answer) -> DayReport {
+        let part = PartReport {
+            day: 8,
+            part: 2,
+            sample: None,
+            outcome: Outcome::Solved(answer),
+            cached: false,
+            expected: None,
+            checksum: String::new(),
+            solve_time: Duration::ZERO,
+            memory: None,
+        };
+
+        return DayReport { day: 8, parse_time: Duration::ZERO, parts: vec![part] };
+    }
+
+    #[test]
+    fn json_strings_escape_quotes_and_backslashes() {
+        assert_eq!(json_string("EHZPZHP"), "\"EHZPZHP\"");
+        assert_eq!(json_string("say \"hi\""), "\"say \\\"hi\\\"\"");
+        assert_eq!(json_string("C:\\inputs"), "\"C:\\\\inputs\"");
+    }
+
+    #[test]
+    fn json_strings_escape_control_characters() {
+        assert_eq!(json_string("line\nnext\r\tend"), "\"line\\nnext\\r\\tend\"");
+        assert_eq!(json_string("bell\u{7}"), "\"bell\\u0007\"");
+        assert_eq!(json_string("µs"), "\"µs\"");
+    }
+
+    #[test]
+    fn csv_fields_are_quoted_only_when_needed() {
+        assert_eq!(csv_field("pass"), "pass");
+        assert_eq!(csv_field("Expected a number, got 'x'"), "\"Expected a number, got 'x'\"");
+        assert_eq!(csv_field("line 1\nline 2"), "\"line 1\nline 2\"");
+    }
+
+    #[test]
+    fn csv_fields_double_embedded_quotes() {
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+
+    #[test]
+    fn json_answers_beyond_doubles_are_strings() {
+        let json = |answer: Answer| json_records(&[report_with_answer(answer)]);
+
+        assert!(json(Answer::Int((1 << 53) - 1)).contains("\"answer\": 9007199254740991,"));
+        assert!(json(Answer::Int(-(1 << 53) + 1)).contains("\"answer\": -9007199254740991,"));
+        assert!(json(Answer::Int(1 << 53)).contains("\"answer\": \"9007199254740992\","));
+        assert!(json(Answer::from(u64::MAX)).contains("\"answer\": \"18446744073709551615\","));
+    }
+}
@@ -0,0 +1,190 @@
+use std::io;
+use std::time::{Duration, Instant};
+
+use rayon::prelude::*;
+
+use crate::answers::{self, Answers, KnownAnswer, Status};
+use crate::cache::AnswerCache;
+use crate::cancel;
+use crate::crash::{self, Crash};
+use crate::error::Error;
+use crate::input::{bundled_inputs_dir, InputSource};
+use crate::memory::{self, MemoryStats};
+use crate::solution::{Answer, Puzzle, Sample};
+
+/// The input a day is solved against
+pub struct RunInput {
+    pub source: InputSource,
+    /// File name the answers are recorded under, when the input is one we have answers for
+    pub name: Option<String>,
+    pub sample: Option<u8>,
+}
+
+impl RunInput {
+    pub fn puzzle(source: &InputSource) -> Self {
+        // the default inputs are the user's own, and like a file given on its own, are
+        // matched to the answers by checksum instead
+        let name = match source {
+            InputSource::File(_) | InputSource::Stdin | InputSource::Auto => None,
+            InputSource::Directory(_) => Some(String::from(answers::PUZZLE_INPUT)),
+        };
+
+        return RunInput { source: source.clone(), name, sample: None };
+    }
+
+    pub fn sample(day: u8, number: u8, sample: &Sample) -> Self {
+        let path = bundled_inputs_dir().join(format!("day{}", day)).join(sample.file);
+
+        return RunInput {
+            source: InputSource::File(path),
+            name: Some(sample.file.to_owned()),
+            sample: Some(number),
+        };
+    }
+}
+
+/// How solving a part ended
+#[derive(Debug)]
+pub enum Outcome {
+    Solved(Answer),
+    /// Cancelled for running past the time limit
+    TimedOut,
+    /// Panicked while parsing the input or solving the part
+    Crashed(Crash),
+    /// The input could not be parsed or did not fit the puzzle
+    Failed(Error),
+}
+
+#[derive(Debug)]
+pub struct PartReport {
+    pub day: u8,
+    pub part: u8,
+    pub sample: Option<u8>,
+    pub outcome: Outcome,
+    /// Taken from the answer cache rather than solved in this run
+    pub cached: bool,
+    pub expected: Option<KnownAnswer>,
+    /// Checksum of the input the part was solved from
+    pub checksum: String,
+    pub solve_time: Duration,
+    /// Only measured when built with the `memory-stats` feature
+    pub memory: Option<MemoryStats>,
+}
+
+/// The parts solved from one parsed input, along with how long parsing it took
+#[derive(Debug)]
+pub struct DayReport {
+    pub day: u8,
+    pub parse_time: Duration,
+    pub parts: Vec<PartReport>,
+}
+
+impl PartReport {
+    pub fn answer(&self) -> Option<&Answer> {
+        return match &self.outcome {
+            Outcome::Solved(answer) => Some(answer),
+            Outcome::TimedOut | Outcome::Crashed(_) | Outcome::Failed(_) => None,
+        };
+    }
+
+    pub fn status(&self) -> Status {
+        return match &self.outcome {
+            Outcome::Solved(answer) => answers::status(answer, self.expected.as_ref(), &self.checksum),
+            Outcome::TimedOut => Status::TimedOut,
+            Outcome::Crashed(_) => Status::Crashed,
+            Outcome::Failed(_) => Status::Error,
+        };
+    }
+
+    pub fn expected_answer(&self) -> Option<&Answer> {
+        return self.expected.as_ref().map(|expected| &expected.answer);
+    }
+
+    pub fn label(&self) -> String {
+        return match self.sample {
+            Some(number) => format!("Day {}, part {}, sample {}", self.day, self.part, number),
+            None => format!("Day {}, part {}", self.day, self.part),
+        };
+    }
+}
+
+/// Parses the input once and solves each of the requested parts from it. With `parallel`
+/// the parts are solved concurrently on the current rayon pool, but reported in order.
+/// Each part is cancelled once it has run for `time_limit`.
+///
+/// Parsing and each part run behind a panic boundary, so a panic marks the part as crashed
+/// rather than ending the run. When parsing panics or fails, every part is marked as such.
+///
+/// Parts found in `cache` for this input and solution version are not solved again, and the
+/// input is only parsed when some part is missing from it.
+pub fn solve(
+    puzzle: &dyn Puzzle,
+    parts: &[u8],
+    input: &RunInput,
+    answers: &Answers,
+    cache: Option<&AnswerCache>,
+    parallel: bool,
+    time_limit: Option<Duration>,
+) -> io::Result<DayReport> {
+    let raw_input = input.source.read(puzzle.day())?;
+    let checksum = answers::checksum(&raw_input);
+
+    let cached_answer = |part: u8| cache?.get(puzzle.day(), part, &checksum, puzzle.version());
+    let all_cached = parts.iter().all(|part| cached_answer(*part).is_some());
+
+    let parse_start = Instant::now();
+    let parsed = (!all_cached).then(|| crash::catch(|| puzzle.parse(&raw_input)));
+    let parse_time = parse_start.elapsed();
+
+    let solve_part = |part: &u8| {
+        let cached = cached_answer(*part);
+
+        let solve_start = Instant::now();
+        let (outcome, memory) = match (cached, &parsed) {
+            (Some(answer), _) => (Outcome::Solved(answer.clone()), None),
+            (None, Some(Ok(Ok(parsed)))) => {
+                let (answer, memory) = memory::measure(|| {
+                    crash::catch(|| cancel::run_with_limit(time_limit, || puzzle.solve(*part, parsed.as_ref())))
+                });
+
+                let outcome = match answer {
+                    Ok(Some(Ok(answer))) => Outcome::Solved(answer),
+                    Ok(Some(Err(error))) => Outcome::Failed(error),
+                    Ok(None) => Outcome::TimedOut,
+                    Err(crash) => Outcome::Crashed(crash),
+                };
+
+                (outcome, memory)
+            }
+            (None, Some(Ok(Err(error)))) => (Outcome::Failed(error.clone()), None),
+            (None, Some(Err(crash))) => (Outcome::Crashed(crash.clone()), None),
+            (None, None) => unreachable!("Day {} was not parsed although part {} is not cached", puzzle.day(), part),
+        };
+        let solve_time = solve_start.elapsed();
+
+        let expected = match input.name.as_deref() {
+            Some(name) => answers.expected(puzzle.day(), *part, name),
+            None => answers.expected_for_input(puzzle.day(), *part, &checksum),
+        };
+
+        PartReport {
+            day: puzzle.day(),
+            part: *part,
+            sample: input.sample,
+            outcome,
+            cached: cached.is_some(),
+            expected: expected.cloned(),
+            checksum: checksum.clone(),
+            solve_time,
+            memory,
+        }
+    };
+
+    let reports = if parallel {
+        parts.par_iter().map(solve_part).collect::<Vec<_>>()
+    } else {
+        parts.iter().map(solve_part).collect::<Vec<_>>()
+    };
+
+    return Ok(DayReport { day: puzzle.day(), parse_time, parts: reports });
+}
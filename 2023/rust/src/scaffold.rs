@@ -0,0 +1,227 @@
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+const MOD_TEMPLATE: &str = "\
+use crate::error::Result;
+use crate::solution::{solve_input, Answer, Sample, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day{day};
+
+/// Parses the puzzle input and solves part 1 of it
+pub fn part1(input: &str) -> Result<Answer> {
+    return solve_input(&Day{day}, 1, input);
+}
+
+/// Parses the puzzle input and solves part 2 of it
+pub fn part2(input: &str) -> Result<Answer> {
+    return solve_input(&Day{day}, 2, input);
+}
+
+impl Solution for Day{day} {
+    type Input = Vec<String>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    const DAY: u8 = {day};
+
+    const SAMPLES: &'static [Sample] = &[
+        Sample { part: 1, file: \"input_sample.txt\" },
+        Sample { part: 2, file: \"input_sample.txt\" },
+    ];
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        return Ok(input.lines().map(|line| line.to_owned()).collect());
+    }
+
+    fn part1(&self, input: &Self::Input) -> Result<Self::Part1> {
+        return part1::solve(input);
+    }
+
+    fn part2(&self, input: &Self::Input) -> Result<Self::Part2> {
+        return part2::solve(input);
+    }
+}
+";
+
+const PART_TEMPLATE: &str = "\
+/*
+
+--- Day {day}: ---
+
+*/
+
+use crate::error::Result;
+
+pub fn solve(_lines: &Vec<String>) -> Result<usize> {
+    todo!(\"Day {day}, part {part}\")
+}
+";
+
+const ANSWERS_TEMPLATE: &str = "
+{day} 1 input.txt ?
+{day} 1 input_sample.txt ?
+{day} 2 input.txt ?
+{day} 2 input_sample.txt ?
+";
+
+/// The crate the new day is added to, so that scaffolding works from any directory
+pub fn crate_dir() -> PathBuf {
+    return PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+}
+
+fn invalid_data(path: &Path, message: &str) -> io::Error {
+    return io::Error::new(io::ErrorKind::InvalidData, format!("{}: {}", path.display(), message));
+}
+
+/// Inserts `line` among the lines that `day_of` recognises, keeping them in day order
+fn insert_in_day_order(text: &str, line: &str, day: u8, day_of: impl Fn(&str) -> Option<u8>) -> Option<String> {
+    let lines = text.lines().collect::<Vec<_>>();
+
+    let last = lines.iter().rposition(|line| day_of(line).is_some())?;
+    let position = lines
+        .iter()
+        .position(|line| day_of(line).is_some_and(|existing| existing > day))
+        .unwrap_or(last + 1);
+
+    let mut updated = lines[..position].to_vec();
+    updated.push(line);
+    updated.extend_from_slice(&lines[position..]);
+
+    return Some(format!("{}\n", updated.join("\n")));
+}
+
+/// Generates the skeleton of a new day in `crate_dir` and wires it into `lib.rs`, the
+/// registry and the answers file. Returns the files that were created or changed.
+pub fn new_day(crate_dir: &Path, day: u8) -> io::Result<Vec<PathBuf>> {
+    let day_dir = crate_dir.join("src").join(format!("day{}", day));
+
+    if day_dir.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already exists", day_dir.display()),
+        ));
+    }
+
+    let fill = |template: &str, part: u8| {
+        template.replace("{day}", &day.to_string()).replace("{part}", &part.to_string())
+    };
+
+    let lib_path = crate_dir.join("src").join("lib.rs");
+    let registry_path = crate_dir.join("src").join("registry.rs");
+    let answers_path = crate_dir.join("answers.txt");
+
+    // check every file can be updated before creating anything
+    let lib = fs::read_to_string(&lib_path)?;
+    let registry = fs::read_to_string(&registry_path)?;
+
+    let module_line = format!("pub mod day{};", day);
+    let lib = insert_in_day_order(&lib, &module_line, day, |line| {
+        line.strip_prefix("pub mod day")?.strip_suffix(';')?.parse().ok()
+    })
+    .ok_or_else(|| invalid_data(&lib_path, "no 'pub mod dayN;' lines to add the new day to"))?;
+
+    let use_line = format!("use crate::day{0}::Day{0};", day);
+    let entry_line = format!("    &Day{},", day);
+    let registry = insert_in_day_order(&registry, &use_line, day, |line| {
+        line.strip_prefix("use crate::day")?.split_once("::")?.0.parse().ok()
+    })
+    .and_then(|registry| {
+        insert_in_day_order(&registry, &entry_line, day, |line| {
+            line.trim().strip_prefix("&Day")?.strip_suffix(',')?.parse().ok()
+        })
+    })
+    .ok_or_else(|| invalid_data(&registry_path, "could not find the list of solutions"))?;
+
+    fs::create_dir_all(&day_dir)?;
+    fs::write(day_dir.join("mod.rs"), fill(MOD_TEMPLATE, 0))?;
+    fs::write(day_dir.join("part1.rs"), fill(PART_TEMPLATE, 1))?;
+    fs::write(day_dir.join("part2.rs"), fill(PART_TEMPLATE, 2))?;
+    fs::write(day_dir.join("input_sample.txt"), "")?;
+
+    fs::write(&lib_path, lib)?;
+    fs::write(&registry_path, registry)?;
+    OpenOptions::new()
+        .append(true)
+        .open(&answers_path)?
+        .write_all(fill(ANSWERS_TEMPLATE, 0).as_bytes())?;
+
+    return Ok(vec![
+        day_dir.join("mod.rs"),
+        day_dir.join("part1.rs"),
+        day_dir.join("part2.rs"),
+        day_dir.join("input_sample.txt"),
+        lib_path,
+        registry_path,
+        answers_path,
+    ]);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+    use std::io;
+    use std::path::PathBuf;
+    use std::process;
+
+    use super::{insert_in_day_order, new_day};
+
+    fn module_day(line: &str) -> Option<u8> {
+        return line.strip_prefix("pub mod day")?.strip_suffix(';')?.parse().ok();
+    }
+
+    #[test]
+    fn day_goes_between_the_days_around_it() {
+        let text = "pub mod answer;\npub mod day1;\npub mod day10;\npub mod error;\n";
+
+        assert_eq!(
+            insert_in_day_order(text, "pub mod day2;", 2, module_day).as_deref(),
+            Some("pub mod answer;\npub mod day1;\npub mod day2;\npub mod day10;\npub mod error;\n")
+        );
+    }
+
+    #[test]
+    fn day_goes_after_the_last_day() {
+        let text = "pub mod day1;\npub mod day2;\npub mod error;\n";
+
+        assert_eq!(
+            insert_in_day_order(text, "pub mod day12;", 12, module_day).as_deref(),
+            Some("pub mod day1;\npub mod day2;\npub mod day12;\npub mod error;\n")
+        );
+    }
+
+    #[test]
+    fn nowhere_to_add_the_day_is_none() {
+        assert_eq!(insert_in_day_order("pub mod error;\n", "pub mod day1;", 1, module_day), None);
+    }
+
+    #[test]
+    fn existing_day_is_left_alone() {
+        let crate_dir = env::temp_dir().join(format!("aoc-2023-scaffold-{}", process::id()));
+        fs::create_dir_all(crate_dir.join("src")).unwrap();
+        fs::write(crate_dir.join("src").join("lib.rs"), "pub mod day1;\npub mod error;\n").unwrap();
+        fs::write(
+            crate_dir.join("src").join("registry.rs"),
+            "use crate::day1::Day1;\n\npub static SOLUTIONS: &[&dyn Puzzle] = &[\n    &Day1,\n];\n",
+        )
+        .unwrap();
+        fs::write(crate_dir.join("answers.txt"), "1 1 input.txt 142\n").unwrap();
+
+        let files = ["src/lib.rs", "src/registry.rs", "answers.txt"].map(|file| crate_dir.join(file));
+        let read_all = |files: &[PathBuf]| files.iter().map(|file| fs::read_to_string(file).unwrap()).collect::<Vec<_>>();
+
+        let first = new_day(&crate_dir, 2).map(|_| read_all(&files));
+        let second = new_day(&crate_dir, 2).map_err(|error| error.kind());
+        let after_second = read_all(&files);
+        fs::remove_dir_all(&crate_dir).unwrap();
+
+        let first = first.unwrap();
+        assert!(first[0].contains("pub mod day2;") && first[1].contains("&Day2,") && first[2].contains("2 1 input.txt ?"));
+        assert_eq!(second.unwrap_err(), io::ErrorKind::AlreadyExists);
+        assert_eq!(after_second, first);
+    }
+}
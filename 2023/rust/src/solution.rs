@@ -0,0 +1,122 @@
+use std::any::Any;
+use std::fmt::Debug;
+
+use crate::error::{Error, Result};
+use crate::io_utils::normalise_input;
+
+pub use crate::answer::Answer;
+
+/// An example input from the puzzle text, stored next to the day's real input. Its
+/// answer is recorded in the answers file like any other input.
+#[derive(Debug)]
+pub struct Sample {
+    pub part: u8,
+    pub file: &'static str,
+}
+
+/// A single day of the calendar.
+///
+/// The input is parsed once and both parts are solved from the parsed value, so the
+/// answers can be reused by the runner instead of only being printed. Known answers
+/// live in `answers.txt` rather than in the code.
+///
+/// Input that does not fit the puzzle is reported through an [`crate::error::Error`]
+/// pointing at the offending line, instead of a panic.
+pub trait Solution: Sync {
+    type Input: Send + Sync + 'static;
+    type Part1: Into<Answer> + Debug + PartialEq;
+    type Part2: Into<Answer> + Debug + PartialEq;
+
+    const DAY: u8;
+    const PARTS: &'static [u8] = &[1, 2];
+
+    const SAMPLES: &'static [Sample] = &[];
+
+    /// Identifies the solution in the answer cache. Bump it when a change could alter the
+    /// day's answers, so that answers cached by the previous version are not reused.
+    const VERSION: &'static str = "1";
+
+    fn parse(&self, input: &str) -> Result<Self::Input>;
+    fn part1(&self, input: &Self::Input) -> Result<Self::Part1>;
+    fn part2(&self, input: &Self::Input) -> Result<Self::Part2>;
+
+    /// Checks the input against the day's format without solving it, reporting every
+    /// problem found. Days without their own checks report the first problem parsing hits.
+    fn lint(&self, input: &str) -> Vec<Error> {
+        return Solution::parse(self, input).err().into_iter().collect();
+    }
+}
+
+/// Object safe view over a [`Solution`], used by the registry so that days with
+/// different input and answer types can live in one list
+pub trait Puzzle: Sync {
+    fn day(&self) -> u8;
+    fn parts(&self) -> &'static [u8];
+    fn version(&self) -> &'static str;
+    fn parse(&self, input: &str) -> Result<Box<dyn Any + Send + Sync>>;
+    fn solve(&self, part: u8, input: &(dyn Any + Send + Sync)) -> Result<Answer>;
+    fn lint(&self, input: &str) -> Vec<Error>;
+    fn samples(&self) -> &'static [Sample];
+
+    /// The `number`th sample (starting at 1) that applies to the given part
+    fn sample(&self, part: u8, number: u8) -> Option<&'static Sample> {
+        return self
+            .samples()
+            .iter()
+            .filter(|sample| sample.part == part)
+            .nth((number as usize).checked_sub(1)?);
+    }
+}
+
+impl<T: Solution> Puzzle for T {
+    fn day(&self) -> u8 {
+        return T::DAY;
+    }
+
+    fn parts(&self) -> &'static [u8] {
+        return T::PARTS;
+    }
+
+    fn version(&self) -> &'static str {
+        return T::VERSION;
+    }
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Any + Send + Sync>> {
+        let input = Solution::parse(self, input).map_err(|error| error.in_day(T::DAY))?;
+
+        return Ok(Box::new(input));
+    }
+
+    fn solve(&self, part: u8, input: &(dyn Any + Send + Sync)) -> Result<Answer> {
+        let input = input
+            .downcast_ref::<T::Input>()
+            .unwrap_or_else(|| panic!("Input passed to day {} was not parsed by it", T::DAY));
+
+        let answer = match part {
+            1 => self.part1(input).map(Into::into),
+            2 => self.part2(input).map(Into::into),
+            _ => unreachable!("Day {} has no part {}", T::DAY, part),
+        };
+
+        return answer.map_err(|error| error.in_day(T::DAY).in_part(part));
+    }
+
+    fn lint(&self, input: &str) -> Vec<Error> {
+        return Solution::lint(self, input)
+            .into_iter()
+            .map(|error| error.in_day(T::DAY))
+            .collect();
+    }
+
+    fn samples(&self) -> &'static [Sample] {
+        return T::SAMPLES;
+    }
+}
+
+/// Normalises and parses `input`, then solves the given part of it. This is what each day's
+/// `part1` and `part2` functions call, for use without the runner.
+pub fn solve_input(puzzle: &dyn Puzzle, part: u8, input: &str) -> Result<Answer> {
+    let parsed = puzzle.parse(&normalise_input(input))?;
+
+    return puzzle.solve(part, parsed.as_ref());
+}
@@ -0,0 +1,63 @@
+#![allow(clippy::needless_return)]
+
+use std::fs;
+use std::path::PathBuf;
+
+use aoc_2023::solution::{Answer, Solution};
+use aoc_2023::{day5, day7, day9};
+
+fn sample(day: u8, file: &str) -> String {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("src").join(format!("day{}", day)).join(file);
+
+    return fs::read_to_string(&path).unwrap_or_else(|error| panic!("Could not read {}: {}", path.display(), error));
+}
+
+#[test]
+fn parts_solve_from_the_raw_input() {
+    assert_eq!(day7::part1(&sample(7, "input_sample.txt")).unwrap(), Answer::Int(6440));
+    assert_eq!(day7::part2(&sample(7, "input_sample.txt")).unwrap(), Answer::Int(5905));
+    assert_eq!(day5::part2(&sample(5, "input_sample.txt")).unwrap(), Answer::Int(46));
+}
+
+#[test]
+fn both_parts_solve_from_one_parse() {
+    let history = day9::Day9.parse(&sample(9, "input_sample.txt")).unwrap();
+
+    assert_eq!(day9::Day9.part1(&history).unwrap(), 114);
+    assert_eq!(day9::Day9.part2(&history).unwrap(), 2);
+}
+
+#[test]
+fn errors_name_the_day_and_line() {
+    let error = day7::part1("32T3K 765\nKK67 28\n").unwrap_err();
+
+    assert_eq!(error.day, Some(7));
+    assert_eq!(error.line, Some(2));
+}
+
+#[cfg(feature = "checked-arithmetic")]
+#[test]
+fn overflows_are_errors_in_checked_builds() {
+    let error = aoc_2023::day6::part1("Time: 6000\nDistance: 60000\n").unwrap_err();
+
+    assert_eq!(error.day, Some(6));
+    assert!(error.message.starts_with("Overflow in distance"), "{}", error);
+}
+
+#[test]
+fn unwinnable_races_are_errors() {
+    // no hold time beats the record, which the search must not run past the race time for
+    for solve in [aoc_2023::day6::part1, aoc_2023::day6::part2] {
+        let error = solve("Time: 3\nDistance: 100\n").unwrap_err();
+
+        assert_eq!(error.message, "No hold time beats the record");
+    }
+}
+
+#[test]
+fn lcm_does_not_overflow_when_the_result_fits() {
+    let large = 1_u64 << 40;
+
+    assert_eq!(aoc_2023::math::lcm(large, large * 3).unwrap(), large * 3);
+    assert_eq!(aoc_2023::math::calculate_lcm(&[4, 6, 10]).unwrap(), 60);
+}